@@ -1,7 +1,8 @@
 #![doc = include_str!("../README.md")]
 #![deny(missing_docs)]
 
-use std::collections::VecDeque;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
 use std::ops::{Index, IndexMut};
 
 #[cfg(feature = "serde")]
@@ -12,11 +13,114 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 /// Once a deque is full,
 /// when a new item is added,
 /// an element from the opposite end is popped and returned.
+/// This default can be changed with an [`OverflowPolicy`]
+/// passed to [`Deque::with_policy`].
 /// <https://docs.python.org/3/library/collections.html#collections.deque>
 #[derive(Debug, Default, Clone)]
+#[allow(clippy::struct_field_names)]
 pub struct Deque<T> {
     deque: VecDeque<T>,
     maxlen: usize,
+    policy: OverflowPolicy,
+    evicted: u64,
+}
+
+/// Determines what happens when a new element is pushed to a full Deque.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OverflowPolicy {
+    /// Pop and return the element from the opposite end, like Python Deque.
+    #[default]
+    EvictOpposite,
+    /// Leave the deque unchanged and return the new element.
+    RejectNew,
+    /// Pop and return the element from the same end the new element is pushed to.
+    EvictSameEnd,
+}
+
+/// Error returned when accessing an index that is out of bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexError {
+    /// The requested index.
+    pub index: usize,
+    /// The length of the deque at the time of access.
+    pub len: usize,
+}
+
+impl std::fmt::Display for IndexError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "index out of bounds: the len is {} but the index is {}",
+            self.len, self.index
+        )
+    }
+}
+
+impl std::error::Error for IndexError {}
+
+/// Error returned when the deque grew past its maximum length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError {
+    /// The length the deque reached.
+    pub len: usize,
+    /// The maximum length of the deque.
+    pub maxlen: usize,
+}
+
+impl std::fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "capacity exceeded: the len is {} but the maxlen is {}",
+            self.len, self.maxlen
+        )
+    }
+}
+
+impl std::error::Error for CapacityError {}
+
+/// A read-only view into a [`Deque`].
+///
+/// Gives access to the elements and the maximum length
+/// without allowing the deque to be modified.
+#[derive(Debug)]
+pub struct DequeView<'a, T> {
+    deque: &'a VecDeque<T>,
+    maxlen: usize,
+}
+
+/// A maximum length for collecting an iterator into a [`Deque`].
+///
+/// Collecting with `FromIterator` sets the maximum length to the number of elements,
+/// while this keeps only the last elements that fit in the given maximum length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Capped(pub usize);
+
+impl Capped {
+    /// Collects the iterator into a new Deque with this maximum length,
+    /// evicting from the front like [`Deque::push_back`].
+    /// A maximum length of zero always results in an empty deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::{Capped, Deque};
+    ///
+    /// let deque: Deque<i32> = Capped(3).collect_from(0..10);
+    /// assert_eq!(deque.len(), 3);
+    /// assert_eq!(deque.front(), Some(&7));
+    /// assert_eq!(deque.maxlen(), 3);
+    /// ```
+    pub fn collect_from<T, I>(self, iter: I) -> Deque<T>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut deque = Deque::new(self.0);
+        deque.extend(iter);
+        // The elements that did not fit were never part of the new deque
+        deque.reset_eviction_count();
+        deque
+    }
 }
 
 impl<T> Deque<T> {
@@ -40,6 +144,31 @@ impl<T> Deque<T> {
         Self {
             deque: VecDeque::with_capacity(maxlen),
             maxlen,
+            policy: OverflowPolicy::default(),
+            evicted: 0,
+        }
+    }
+
+    /// Creates a new empty Deque with a given maximum length and overflow policy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::{Deque, OverflowPolicy};
+    ///
+    /// let mut deque: Deque<i32> = Deque::with_policy(2, OverflowPolicy::RejectNew);
+    /// deque.push_back(1);
+    /// deque.push_back(2);
+    /// assert_eq!(deque.push_back(3), Some(3));
+    /// assert_eq!(deque.back(), Some(&2));
+    /// ```
+    #[must_use]
+    pub fn with_policy(maxlen: usize, policy: OverflowPolicy) -> Self {
+        Self {
+            deque: VecDeque::with_capacity(maxlen),
+            maxlen,
+            policy,
+            evicted: 0,
         }
     }
 
@@ -58,6 +187,8 @@ impl<T> Deque<T> {
         Self {
             deque: VecDeque::from([value]),
             maxlen,
+            policy: OverflowPolicy::default(),
+            evicted: 0,
         }
     }
 
@@ -79,6 +210,8 @@ impl<T> Deque<T> {
         Self {
             deque: VecDeque::from(vec),
             maxlen,
+            policy: OverflowPolicy::default(),
+            evicted: 0,
         }
     }
 
@@ -99,7 +232,201 @@ impl<T> Deque<T> {
     #[must_use]
     pub fn from_vec_deque(mut deque: VecDeque<T>, maxlen: usize) -> Self {
         deque.truncate(maxlen);
-        Self { deque, maxlen }
+        Self {
+            deque,
+            maxlen,
+            policy: OverflowPolicy::default(),
+            evicted: 0,
+        }
+    }
+
+    /// Creates a new Deque with a given maximum length,
+    /// where each element is the result of calling `f` with its index.
+    /// At most `maxlen` elements are created.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<usize> = Deque::from_fn(5, 3, |i| i * 10);
+    /// assert_eq!(deque.len(), 3);
+    /// assert_eq!(deque.get(2), Some(&20));
+    /// ```
+    pub fn from_fn<F>(maxlen: usize, n: usize, f: F) -> Self
+    where
+        F: FnMut(usize) -> T,
+    {
+        Self {
+            deque: (0..n.min(maxlen)).map(f).collect(),
+            maxlen,
+            policy: OverflowPolicy::default(),
+            evicted: 0,
+        }
+    }
+
+    /// Creates a new Deque with a given maximum length,
+    /// filled with `count` clones of the given value.
+    /// At most `maxlen` elements are created.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = Deque::repeat(0, 10, 4);
+    /// assert_eq!(deque.len(), 4);
+    /// assert_eq!(deque.maxlen(), 4);
+    /// ```
+    #[must_use]
+    pub fn repeat(value: T, count: usize, maxlen: usize) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            deque: std::iter::repeat_n(value, count.min(maxlen)).collect(),
+            maxlen,
+            policy: OverflowPolicy::default(),
+            evicted: 0,
+        }
+    }
+
+    /// Creates a new full Deque with a given maximum length,
+    /// filled with `maxlen` default values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = Deque::filled_default(3);
+    /// assert_eq!(deque.len(), 3);
+    /// assert_eq!(deque.front(), Some(&0));
+    /// ```
+    #[must_use]
+    pub fn filled_default(maxlen: usize) -> Self
+    where
+        T: Default + Clone,
+    {
+        Self::repeat(T::default(), maxlen, maxlen)
+    }
+
+    /// Creates a new Deque with a given maximum length
+    /// by pushing clones of the elements of all the given deques in order.
+    /// If there are more than `maxlen` elements in total,
+    /// only the last `maxlen` elements are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let first: Deque<i32> = (vec![1, 2, 3], 3).into();
+    /// let second: Deque<i32> = (vec![4, 5], 2).into();
+    /// let deque = Deque::concat(&[first, second], 4);
+    /// assert_eq!(deque.front(), Some(&2));
+    /// assert_eq!(deque.back(), Some(&5));
+    /// ```
+    #[must_use]
+    pub fn concat(parts: &[Self], maxlen: usize) -> Self
+    where
+        T: Clone,
+    {
+        let mut deque = Self::new(maxlen);
+        for value in parts.iter().flat_map(Self::iter) {
+            deque.push_back(value.clone());
+        }
+        // The elements that did not fit were never part of the new deque
+        deque.reset_eviction_count();
+        deque
+    }
+
+    /// Creates a new Deque from an inner `VecDeque` and a maximum length,
+    /// as returned by [`into_parts`](Self::into_parts).
+    /// If the given `VecDeque` is larger than the maximum length,
+    /// only the first `maxlen` elements are used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::VecDeque;
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = Deque::from_parts(VecDeque::from([1, 2, 3]), 2);
+    /// assert_eq!(deque.len(), 2);
+    /// assert_eq!(deque.maxlen(), 2);
+    /// ```
+    #[must_use]
+    pub fn from_parts(deque: VecDeque<T>, maxlen: usize) -> Self {
+        Self::from_vec_deque(deque, maxlen)
+    }
+
+    /// Creates a new Deque from a given single value and maximum length.
+    /// If the maximum length is zero, the value is dropped and the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = Deque::singleton(1, 3);
+    /// assert_eq!(deque.len(), 1);
+    /// assert_eq!(deque.front(), Some(&1));
+    /// ```
+    pub fn singleton(value: T, maxlen: usize) -> Self {
+        Self::from_vec(vec![value], maxlen)
+    }
+
+    /// Creates a new Deque from two values and a maximum length.
+    /// If the maximum length is less than two,
+    /// only the first `maxlen` values are used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = Deque::pair(1, 2, 3);
+    /// assert_eq!(deque.front(), Some(&1));
+    /// assert_eq!(deque.back(), Some(&2));
+    /// ```
+    #[must_use]
+    pub fn pair(a: T, b: T, maxlen: usize) -> Self {
+        Self::from_vec(vec![a, b], maxlen)
+    }
+
+    /// Splits a `Vec` into consecutive deques of `maxlen` elements,
+    /// each with the given maximum length.
+    /// The last deque holds the remaining elements and may be smaller.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `maxlen` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deques = Deque::chunk_into_deques(vec![1, 2, 3, 4, 5], 2);
+    /// assert_eq!(deques.len(), 3);
+    /// assert_eq!(deques[2].len(), 1);
+    /// ```
+    #[must_use]
+    pub fn chunk_into_deques(vec: Vec<T>, maxlen: usize) -> Vec<Self> {
+        assert!(maxlen > 0, "maxlen must be non-zero");
+        let mut deques = Vec::with_capacity(vec.len().div_ceil(maxlen));
+        let mut current = Self::new(maxlen);
+        for value in vec {
+            current.deque.push_back(value);
+            if current.deque.len() == maxlen {
+                deques.push(std::mem::replace(&mut current, Self::new(maxlen)));
+            }
+        }
+        if !current.is_empty() {
+            deques.push(current);
+        }
+        deques
     }
 
     /// Returns the maximum length of the deque.
@@ -118,6 +445,62 @@ impl<T> Deque<T> {
         self.maxlen
     }
 
+    /// Returns the overflow policy of the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::{Deque, OverflowPolicy};
+    ///
+    /// let deque: Deque<i32> = Deque::new(3);
+    /// assert_eq!(deque.overflow_policy(), OverflowPolicy::EvictOpposite);
+    /// ```
+    #[must_use]
+    pub const fn overflow_policy(&self) -> OverflowPolicy {
+        self.policy
+    }
+
+    /// Returns the total number of elements evicted to make room for new ones
+    /// since the deque was created or the count was last reset.
+    ///
+    /// This counts evictions by [`push_back`](Self::push_back), [`push_front`](Self::push_front)
+    /// and the methods built on them, as well as by [`cycle_push`](Self::cycle_push),
+    /// [`insert_sorted`](Self::insert_sorted) and [`normalize`](Self::normalize).
+    /// A deque built by [`concat`](Self::concat) or [`Capped::collect_from`]
+    /// starts with a count of zero.
+    ///
+    /// Elements rejected under [`OverflowPolicy::RejectNew`] are not counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = Deque::new(2);
+    /// deque.extend([1, 2, 3]);
+    /// assert_eq!(deque.total_evicted(), 1);
+    /// ```
+    #[must_use]
+    pub const fn total_evicted(&self) -> u64 {
+        self.evicted
+    }
+
+    /// Resets the eviction count reported by [`total_evicted`](Self::total_evicted) to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = Deque::new(1);
+    /// deque.extend([1, 2]);
+    /// deque.reset_eviction_count();
+    /// assert_eq!(deque.total_evicted(), 0);
+    /// ```
+    pub const fn reset_eviction_count(&mut self) {
+        self.evicted = 0;
+    }
+
     /// Clears all elements from the deque, making it empty.
     ///
     /// # Examples
@@ -157,6 +540,14 @@ impl<T> Deque<T> {
     /// the front element is popped and returned.
     /// Otherwise, `None` is returned.
     ///
+    /// A full Deque created with [`with_policy`](Self::with_policy)
+    /// handles the new element according to its [`OverflowPolicy`] instead.
+    /// A Deque left overfull by [`set_maxlen_lazy`](Self::set_maxlen_lazy)
+    /// evicts as many elements as needed to get back within its maximum length,
+    /// and only the first evicted element is returned.
+    /// A Deque with a maximum length of zero never stores an element,
+    /// and the given value is returned back as is.
+    ///
     /// # Examples
     ///
     /// ```
@@ -172,9 +563,17 @@ impl<T> Deque<T> {
     /// assert_eq!(deque.len(), 3);
     /// ```
     pub fn push_back(&mut self, value: T) -> Option<T> {
-        if self.deque.len() == self.maxlen {
-            // If at max capacity, pop the front element before pushing
-            let popped = self.deque.pop_front();
+        if self.maxlen == 0 {
+            // A zero length deque can never store the element
+            return Some(value);
+        }
+        if self.deque.len() >= self.maxlen {
+            // If at max capacity, make room according to the overflow policy
+            let popped = match self.policy {
+                OverflowPolicy::EvictOpposite => self.evict_until_room(true),
+                OverflowPolicy::EvictSameEnd => self.evict_until_room(false),
+                OverflowPolicy::RejectNew => return Some(value),
+            };
             self.deque.push_back(value);
             popped
         } else {
@@ -188,6 +587,14 @@ impl<T> Deque<T> {
     /// the back element is popped and returned.
     /// Otherwise, `None` is returned.
     ///
+    /// A full Deque created with [`with_policy`](Self::with_policy)
+    /// handles the new element according to its [`OverflowPolicy`] instead.
+    /// A Deque left overfull by [`set_maxlen_lazy`](Self::set_maxlen_lazy)
+    /// evicts as many elements as needed to get back within its maximum length,
+    /// and only the first evicted element is returned.
+    /// A Deque with a maximum length of zero never stores an element,
+    /// and the given value is returned back as is.
+    ///
     /// # Examples
     ///
     /// ```
@@ -202,9 +609,17 @@ impl<T> Deque<T> {
     /// assert_eq!(deque.len(), 2);
     /// ```
     pub fn push_front(&mut self, value: T) -> Option<T> {
-        if self.deque.len() == self.maxlen {
-            // If at max capacity, pop the back element before pushing
-            let popped = self.deque.pop_back();
+        if self.maxlen == 0 {
+            // A zero length deque can never store the element
+            return Some(value);
+        }
+        if self.deque.len() >= self.maxlen {
+            // If at max capacity, make room according to the overflow policy
+            let popped = match self.policy {
+                OverflowPolicy::EvictOpposite => self.evict_until_room(false),
+                OverflowPolicy::EvictSameEnd => self.evict_until_room(true),
+                OverflowPolicy::RejectNew => return Some(value),
+            };
             self.deque.push_front(value);
             popped
         } else {
@@ -213,6 +628,53 @@ impl<T> Deque<T> {
         }
     }
 
+    /// Evicts elements from the front or the back until there is room for one more element,
+    /// and returns the first evicted element.
+    /// The maximum length must be non-zero.
+    fn evict_until_room(&mut self, from_front: bool) -> Option<T> {
+        let mut first = None;
+        while self.deque.len() >= self.maxlen {
+            let popped = if from_front {
+                self.deque.pop_front()
+            } else {
+                self.deque.pop_back()
+            };
+            if popped.is_some() {
+                self.evicted += 1;
+            }
+            first = first.or(popped);
+        }
+        first
+    }
+
+    /// Pushes an element to the back, silently dropping the front element if the deque is full.
+    ///
+    /// This is the shift register pattern: once filled,
+    /// the deque stays at its maximum length and every new value shifts the window by one.
+    /// Unlike [`push_back`](Self::push_back), the evicted element is not returned,
+    /// and the front element is always evicted regardless of the overflow policy,
+    /// like [`cycle_push`](Self::cycle_push).
+    /// A deque with a maximum length of zero drops the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+    /// deque.shift_fill(4);
+    /// assert_eq!(deque.len(), 3);
+    /// assert_eq!(deque.front(), Some(&2));
+    /// assert_eq!(deque.back(), Some(&4));
+    /// ```
+    pub fn shift_fill(&mut self, value: T) {
+        if self.maxlen == 0 {
+            return;
+        }
+        self.evict_until_room(true);
+        self.deque.push_back(value);
+    }
+
     /// Removes the first element and returns it,
     /// or `None` if the deque is empty.
     ///
@@ -391,10 +853,29 @@ impl<T> Deque<T> {
     /// assert_eq!(&c[..], b);
     /// ```
     #[must_use]
-    pub fn iter(&self) -> std::collections::vec_deque::Iter<T> {
+    pub fn iter(&self) -> std::collections::vec_deque::Iter<'_, T> {
         self.deque.iter()
     }
 
+    /// Returns a front-to-back iterator yielding clones of the elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<String> = (vec!["a".to_string(), "b".to_string()], 2).into();
+    /// let owned: Vec<String> = deque.iter_cloned().collect();
+    /// assert_eq!(owned, vec!["a", "b"]);
+    /// assert_eq!(deque.len(), 2);
+    /// ```
+    pub fn iter_cloned(&self) -> impl Iterator<Item = T> + '_
+    where
+        T: Clone,
+    {
+        self.deque.iter().cloned()
+    }
+
     /// Returns a front-to-back mutable iterator.
     ///
     /// This allows modifying each element in the deque in place.
@@ -418,86 +899,196 @@ impl<T> Deque<T> {
     /// assert_eq!(&c[..], b);
     /// ```
     #[must_use]
-    pub fn iter_mut(&mut self) -> std::collections::vec_deque::IterMut<T> {
+    pub fn iter_mut(&mut self) -> std::collections::vec_deque::IterMut<'_, T> {
         self.deque.iter_mut()
     }
 
-    /// Returns the number of elements the deque can hold without reallocating.
-    /// If the number is larger than the max size,
-    /// returns the max number of elements instead.
+    /// Returns a pair of slices which contain, in order, the contents of the deque.
+    ///
+    /// The second slice is non-empty when the elements wrap around the end of the internal buffer.
     ///
     /// # Examples
     ///
     /// ```
     /// use fixed_deque::Deque;
     ///
-    /// let deque: Deque<i32> = Deque::new(10);
-    /// assert!(deque.capacity() >= 10);
+    /// let deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+    /// assert_eq!(deque.as_slices(), (&[1, 2, 3][..], &[][..]));
     /// ```
     #[must_use]
-    pub fn capacity(&self) -> usize {
-        self.deque.capacity().min(self.maxlen)
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        self.deque.as_slices()
     }
-}
 
-// Implement From for single value.
-impl<T> From<(T, usize)> for Deque<T> {
-    /// Creates a new Deque from a single value and a maximum length.
+    /// Moves the elements so that they start at the beginning of the internal buffer
+    /// and occupy a single contiguous region.
+    ///
+    /// This is the same as `make_contiguous` without returning the slice,
+    /// and can be used as a hint to avoid split slices on the next batch of operations.
     ///
     /// # Examples
     ///
     /// ```
     /// use fixed_deque::Deque;
     ///
-    /// let deque: Deque<i32> = (1, 3).into();
-    /// assert_eq!(deque.len(), 1);
-    /// assert_eq!(deque.get(0), Some(&1));
+    /// let mut deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+    /// deque.push_back(4);
+    /// deque.align_to_front();
+    /// assert!(deque.as_slices().1.is_empty());
     /// ```
-    fn from((value, maxlen): (T, usize)) -> Self {
-        Self {
-            deque: VecDeque::from([value]),
-            maxlen,
-        }
+    pub fn align_to_front(&mut self) {
+        self.deque.make_contiguous();
     }
-}
 
-// Implement From for arrays.
-impl<T, const N: usize> From<([T; N], usize)> for Deque<T> {
-    /// Creates a new Deque from an array and a maximum length.
-    /// If the array is larger than the maximum length,
-    /// only the first `maxlen` elements are used.
+    /// Returns the number of elements the deque can hold without reallocating.
+    /// If the number is larger than the max size,
+    /// returns the max number of elements instead,
+    /// or the current length for a deque left overfull by
+    /// [`set_maxlen_lazy`](Self::set_maxlen_lazy).
     ///
     /// # Examples
     ///
     /// ```
     /// use fixed_deque::Deque;
     ///
-    /// let deque: Deque<i32> = ([1, 2, 3], 16).into();
-    /// assert_eq!(deque.len(), 3);
-    /// assert_eq!(deque.maxlen(), 16);
-    /// assert_eq!(deque.get(0), Some(&1));
-    /// assert_eq!(deque.get(2), Some(&3));
+    /// let deque: Deque<i32> = Deque::new(10);
+    /// assert!(deque.capacity() >= 10);
+    /// ```
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.deque.capacity().min(self.maxlen).max(self.deque.len())
+    }
+
+    /// Returns `true` if the given values appear in the deque in the same order,
+    /// though not necessarily next to each other.
+    /// An empty sequence is always contained.
     ///
-    /// let deque: Deque<f64> = ([1.0, 2.0, 3.0], 2).into();
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+    /// assert!(deque.contains_subsequence(&[1, 3]));
+    /// assert!(!deque.contains_subsequence(&[3, 1]));
+    /// ```
+    pub fn contains_subsequence(&self, seq: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        let mut remaining = seq.iter().peekable();
+        for value in &self.deque {
+            match remaining.peek() {
+                Some(&next) if next == value => {
+                    remaining.next();
+                }
+                Some(_) => {}
+                None => break,
+            }
+        }
+        remaining.peek().is_none()
+    }
+
+    /// Returns a front-to-back iterator that yields each element with its index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![10, 20], 3).into();
+    /// let mut iter = deque.iter_indexed();
+    /// assert_eq!(iter.next(), Some((0, &10)));
+    /// assert_eq!(iter.next(), Some((1, &20)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn iter_indexed(&self) -> impl Iterator<Item = (usize, &T)> {
+        self.deque.iter().enumerate()
+    }
+
+    /// Returns a reference to the element at the given index,
+    /// clamping an out-of-range index to the last element.
+    ///
+    /// Unlike [`get`](Self::get), an index past the end does not return `None`
+    /// but the back element instead.
+    /// `None` is only returned when the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+    /// assert_eq!(deque.get_clamped(1), Some(&2));
+    /// assert_eq!(deque.get_clamped(9), Some(&3));
+    /// ```
+    #[must_use]
+    pub fn get_clamped(&self, index: usize) -> Option<&T> {
+        self.deque.get(index).or_else(|| self.deque.back())
+    }
+
+    /// Removes up to `n` elements from the front and returns them in pop order.
+    /// If `n` is larger than the length of the deque, all elements are removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+    /// assert_eq!(deque.pop_front_n(2), vec![1, 2]);
     /// assert_eq!(deque.len(), 2);
-    /// assert_eq!(deque.maxlen(), 2);
-    /// assert_eq!(deque.get(0), Some(&1.0));
-    /// assert_eq!(deque.get(1), Some(&2.0));
     /// ```
-    fn from((array, maxlen): ([T; N], usize)) -> Self {
-        let deque = if N > maxlen {
-            // If the array size exceeds maxlen, take only the first `maxlen` elements.
-            VecDeque::from(array.into_iter().take(maxlen).collect::<Vec<_>>())
-        } else {
-            VecDeque::from(array)
-        };
-        Self { deque, maxlen }
+    pub fn pop_front_n(&mut self, n: usize) -> Vec<T> {
+        let n = n.min(self.deque.len());
+        self.deque.drain(..n).collect()
     }
-}
 
-// Implement From for Vec.
-impl<T> From<(Vec<T>, usize)> for Deque<T> {
-    /// Creates a new Deque from a Vec and a maximum length.
+    /// Removes up to `n` elements from the back and returns them in pop order.
+    /// If `n` is larger than the length of the deque, all elements are removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+    /// assert_eq!(deque.pop_back_n(2), vec![4, 3]);
+    /// assert_eq!(deque.len(), 2);
+    /// ```
+    pub fn pop_back_n(&mut self, n: usize) -> Vec<T> {
+        let start = self.deque.len().saturating_sub(n);
+        self.deque.drain(start..).rev().collect()
+    }
+
+    /// Returns a raw pointer to the elements of the deque, front to back.
+    ///
+    /// The deque is made contiguous first, which may reorder the internal buffer.
+    /// The pointer is only valid until the deque is mutated or dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+    /// let ptr = deque.as_ptr();
+    /// assert!(std::ptr::eq(ptr, &deque[0]));
+    /// ```
+    pub fn as_ptr(&mut self) -> *const T {
+        self.deque.make_contiguous().as_ptr()
+    }
+
+    /// Returns a raw mutable pointer to the elements of the deque, front to back.
+    ///
+    /// The deque is made contiguous first, which may reorder the internal buffer.
+    /// The pointer is only valid until the deque is mutated or dropped.
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.deque.make_contiguous().as_mut_ptr()
+    }
+
+    /// Clones the elements of the deque into the given `Vec`, front to back.
+    /// The destination is cleared first so its allocation can be reused.
     ///
     /// # Examples
     ///
@@ -505,336 +1096,4477 @@ impl<T> From<(Vec<T>, usize)> for Deque<T> {
     /// use fixed_deque::Deque;
     ///
     /// let deque: Deque<i32> = (vec![1, 2, 3], 3).into();
-    /// assert_eq!(deque.len(), 3);
-    /// assert_eq!(deque.get(0), Some(&1));
-    /// assert_eq!(deque.get(2), Some(&3));
+    /// let mut dst = vec![9, 9, 9, 9];
+    /// deque.clone_into_vec(&mut dst);
+    /// assert_eq!(dst, vec![1, 2, 3]);
     /// ```
-    fn from((mut vec, maxlen): (Vec<T>, usize)) -> Self {
-        vec.truncate(maxlen);
-        Self {
-            deque: VecDeque::from(vec),
-            maxlen,
+    pub fn clone_into_vec(&self, dst: &mut Vec<T>)
+    where
+        T: Clone,
+    {
+        dst.clear();
+        dst.extend(self.deque.iter().cloned());
+    }
+
+    /// Replaces the element at the given index and returns the previous element.
+    ///
+    /// # Errors
+    ///
+    /// Returns the given value back as `Err` if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+    /// assert_eq!(deque.replace(1, 20), Ok(2));
+    /// assert_eq!(deque.get(1), Some(&20));
+    /// assert_eq!(deque.replace(5, 50), Err(50));
+    /// ```
+    pub fn replace(&mut self, index: usize, value: T) -> Result<T, T> {
+        match self.deque.get_mut(index) {
+            Some(element) => Ok(std::mem::replace(element, value)),
+            None => Err(value),
         }
     }
-}
 
-// Implement From for VecDeque.
-impl<T> From<(VecDeque<T>, usize)> for Deque<T> {
-    /// Creates a new Deque from a `VecDeque` and a maximum length.
+    /// Returns `true` if each element is less than or equal to the next one.
+    /// Empty and single-element deques are trivially monotonic.
     ///
     /// # Examples
     ///
     /// ```
-    /// use std::collections::VecDeque;
     /// use fixed_deque::Deque;
     ///
-    /// let vec_deque: VecDeque<i32> = VecDeque::from(vec![1, 2, 3]);
-    /// let deque: Deque<i32> = (vec_deque, 3).into();
-    /// assert_eq!(deque.len(), 3);
-    /// assert_eq!(deque.get(0), Some(&1));
+    /// let deque: Deque<i32> = (vec![1, 2, 2, 3], 4).into();
+    /// assert!(deque.is_monotonic_increasing());
+    /// assert!(!deque.is_strictly_increasing());
     /// ```
-    fn from((mut deque, maxlen): (VecDeque<T>, usize)) -> Self {
-        deque.truncate(maxlen);
-        Self { deque, maxlen }
+    #[must_use]
+    pub fn is_monotonic_increasing(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.all_adjacent(|a, b| a <= b)
     }
-}
 
-impl<T: PartialEq> PartialEq for Deque<T> {
-    fn eq(&self, other: &Self) -> bool {
-        self.deque == other.deque
+    /// Returns `true` if each element is greater than or equal to the next one.
+    /// Empty and single-element deques are trivially monotonic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![3, 2, 1], 3).into();
+    /// assert!(deque.is_monotonic_decreasing());
+    /// ```
+    #[must_use]
+    pub fn is_monotonic_decreasing(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.all_adjacent(|a, b| a >= b)
     }
-}
 
-impl<T: PartialEq> PartialEq<VecDeque<T>> for Deque<T> {
-    fn eq(&self, other: &VecDeque<T>) -> bool {
-        &self.deque == other
+    /// Returns `true` if each element is less than the next one.
+    /// Empty and single-element deques are trivially monotonic.
+    #[must_use]
+    pub fn is_strictly_increasing(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.all_adjacent(|a, b| a < b)
     }
-}
 
-impl<T: PartialEq> PartialEq<Deque<T>> for VecDeque<T> {
-    fn eq(&self, other: &Deque<T>) -> bool {
-        self == &other.deque
+    /// Returns `true` if each element is greater than the next one.
+    /// Empty and single-element deques are trivially monotonic.
+    #[must_use]
+    pub fn is_strictly_decreasing(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.all_adjacent(|a, b| a > b)
     }
-}
 
-impl<T: Eq> Eq for Deque<T> {}
+    /// Returns `true` if the predicate holds for every pair of adjacent elements.
+    fn all_adjacent<F>(&self, mut f: F) -> bool
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        self.pairwise().all(|(a, b)| f(a, b))
+    }
 
-impl<T> Index<usize> for Deque<T> {
-    type Output = T;
+    /// Returns the running accumulation of the elements, front to back.
+    ///
+    /// Each value in the result is `f` applied to the previous accumulated value
+    /// (or `init` for the first element) and the element at that position,
+    /// so the result has the same length as the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+    /// assert_eq!(deque.prefix_scan(0, |acc, x| acc + x), vec![1, 3, 6]);
+    /// ```
+    pub fn prefix_scan<B, F>(&self, init: B, mut f: F) -> Vec<B>
+    where
+        B: Clone,
+        F: FnMut(&B, &T) -> B,
+    {
+        let mut acc = init;
+        self.deque
+            .iter()
+            .map(|value| {
+                acc = f(&acc, value);
+                acc.clone()
+            })
+            .collect()
+    }
 
-    fn index(&self, index: usize) -> &Self::Output {
-        &self.deque[index]
+    /// Folds the elements front to back with a fallible function,
+    /// stopping at the first error.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error produced by `f`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+    /// let sum: Result<i32, ()> = deque.try_fold(0, |acc, &x| Ok(acc + x));
+    /// assert_eq!(sum, Ok(6));
+    /// ```
+    pub fn try_fold<B, E, F>(&self, init: B, f: F) -> Result<B, E>
+    where
+        F: FnMut(B, &T) -> Result<B, E>,
+    {
+        self.deque.iter().try_fold(init, f)
     }
-}
 
-impl<T> IndexMut<usize> for Deque<T> {
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        &mut self.deque[index]
+    /// Returns a consuming back-to-front iterator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+    /// let reversed: Vec<i32> = deque.into_iter_rev().collect();
+    /// assert_eq!(reversed, vec![3, 2, 1]);
+    /// ```
+    pub fn into_iter_rev(self) -> std::iter::Rev<std::collections::vec_deque::IntoIter<T>> {
+        self.deque.into_iter().rev()
     }
-}
 
-// Implement IntoIterator for owned Deque
-impl<T> IntoIterator for Deque<T> {
-    type Item = T;
-    type IntoIter = std::collections::vec_deque::IntoIter<T>;
+    /// Swaps this deque with another one in O(1),
+    /// including the contents, maximum length, overflow policy and eviction count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut front: Deque<i32> = (vec![1, 2], 2).into();
+    /// let mut back: Deque<i32> = (vec![3, 4, 5], 5).into();
+    /// front.swap_with(&mut back);
+    /// assert_eq!(front.maxlen(), 5);
+    /// assert_eq!(back.maxlen(), 2);
+    /// ```
+    pub const fn swap_with(&mut self, other: &mut Self) {
+        std::mem::swap(self, other);
+    }
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.deque.into_iter()
+    /// Shrinks the allocation of the deque with a lower bound.
+    ///
+    /// The allocation will remain at least as large as both the length
+    /// and the given minimum capacity.
+    /// Since the deque never holds more than `maxlen` elements,
+    /// a minimum capacity above `maxlen` only keeps memory that will never be used,
+    /// while shrinking below `maxlen` means later pushes may reallocate again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = Deque::new(100);
+    /// deque.push_back(1);
+    /// deque.shrink_to(10);
+    /// assert!(deque.capacity() >= 10);
+    /// assert!(deque.capacity() < 100);
+    /// ```
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.deque.shrink_to(min_capacity);
     }
-}
 
-// Implement IntoIterator for Deque references
-impl<'a, T> IntoIterator for &'a Deque<T> {
-    type Item = &'a T;
-    type IntoIter = std::collections::vec_deque::Iter<'a, T>;
+    /// Pushes clones of all elements of the frame to the back of the deque
+    /// and returns the elements that did not fit, in the order they were displaced.
+    ///
+    /// With the default [`OverflowPolicy::EvictOpposite`] these are the evicted front elements.
+    /// Under [`OverflowPolicy::EvictSameEnd`] they are the evicted back elements,
+    /// and under [`OverflowPolicy::RejectNew`] they are the rejected elements of the frame.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = (vec![1, 2, 3], 4).into();
+    /// let evicted = deque.push_frame(&[4, 5, 6]);
+    /// assert_eq!(evicted, vec![1, 2]);
+    /// assert_eq!(deque.front(), Some(&3));
+    /// ```
+    pub fn push_frame(&mut self, frame: &[T]) -> Vec<T>
+    where
+        T: Clone,
+    {
+        frame
+            .iter()
+            .filter_map(|value| self.push_back(value.clone()))
+            .collect()
+    }
+
+    /// Returns the index of the first maximum element, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![3, 1, 4, 1, 5], 5).into();
+    /// assert_eq!(deque.position_of_max(), Some(4));
+    /// ```
+    #[must_use]
+    pub fn position_of_max(&self) -> Option<usize>
+    where
+        T: Ord,
+    {
+        // `max_by` returns the last maximum, so iterate in reverse to get the first one
+        self.deque
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by(|(_, a), (_, b)| a.cmp(b))
+            .map(|(index, _)| index)
+    }
+
+    /// Returns the index of the first minimum element, or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![3, 1, 4, 1, 5], 5).into();
+    /// assert_eq!(deque.position_of_min(), Some(1));
+    /// ```
+    #[must_use]
+    pub fn position_of_min(&self) -> Option<usize>
+    where
+        T: Ord,
+    {
+        self.deque
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.cmp(b))
+            .map(|(index, _)| index)
+    }
+
+    /// Returns the number of unique elements in the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2, 2, 3, 3, 3], 6).into();
+    /// assert_eq!(deque.count_distinct(), 3);
+    /// ```
+    #[must_use]
+    pub fn count_distinct(&self) -> usize
+    where
+        T: Eq + Hash,
+    {
+        self.deque.iter().collect::<HashSet<_>>().len()
+    }
+
+    /// Returns a new deque with the same maximum length,
+    /// keeping only the last occurrence of each distinct element in their original relative order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2, 1, 3, 2], 5).into();
+    /// let unique = deque.unique_last();
+    /// assert_eq!(unique, Deque::from_vec(vec![1, 3, 2], 5));
+    /// ```
+    #[must_use]
+    pub fn unique_last(&self) -> Self
+    where
+        T: Clone + Eq + Hash,
+    {
+        let mut seen = HashSet::new();
+        let mut kept: Vec<T> = self
+            .deque
+            .iter()
+            .rev()
+            .filter(|value| seen.insert(*value))
+            .cloned()
+            .collect();
+        kept.reverse();
+        Self {
+            deque: kept.into(),
+            maxlen: self.maxlen,
+            policy: self.policy,
+            evicted: 0,
+        }
+    }
+
+    /// Returns the most frequently occurring element, or `None` if the deque is empty.
+    /// Ties are broken by the first occurrence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2, 2, 3, 3, 3], 6).into();
+    /// assert_eq!(deque.mode(), Some(&3));
+    /// ```
+    #[must_use]
+    pub fn mode(&self) -> Option<&T>
+    where
+        T: Eq + Hash,
+    {
+        let counts = self.histogram();
+        let mut mode: Option<(&T, usize)> = None;
+        for value in &self.deque {
+            let count = counts[value];
+            if mode.is_none_or(|(_, max)| count > max) {
+                mode = Some((value, count));
+            }
+        }
+        mode.map(|(value, _)| value)
+    }
+
+    /// Returns a map from each distinct element to the number of times it occurs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 1, 2], 3).into();
+    /// let histogram = deque.histogram();
+    /// assert_eq!(histogram[&1], 2);
+    /// assert_eq!(histogram[&2], 1);
+    /// ```
+    #[must_use]
+    pub fn histogram(&self) -> HashMap<&T, usize>
+    where
+        T: Eq + Hash,
+    {
+        let mut counts = HashMap::new();
+        for value in &self.deque {
+            *counts.entry(value).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Returns an iterator over each pair of adjacent elements, front to back.
+    /// Empty and single-element deques yield nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+    /// let mut pairs = deque.pairwise();
+    /// assert_eq!(pairs.next(), Some((&1, &2)));
+    /// assert_eq!(pairs.next(), Some((&2, &3)));
+    /// assert_eq!(pairs.next(), None);
+    /// ```
+    pub fn pairwise(&self) -> impl Iterator<Item = (&T, &T)> {
+        self.deque.iter().zip(self.deque.iter().skip(1))
+    }
+
+    /// Returns the sum of each contiguous window of the given size, front to back.
+    /// The result is empty if the window is larger than the deque.
+    ///
+    /// The deque is made contiguous first, which may reorder the internal buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+    /// assert_eq!(deque.rolling_sum(2), vec![3, 5, 7]);
+    /// ```
+    pub fn rolling_sum(&mut self, window: usize) -> Vec<T>
+    where
+        T: Copy + std::iter::Sum,
+    {
+        self.windows(window)
+            .map(|values| values.iter().copied().sum())
+            .collect()
+    }
+
+    /// Returns an iterator over contiguous windows of the given size.
+    fn windows(&mut self, window: usize) -> std::slice::Windows<'_, T> {
+        assert!(window > 0, "window size must be non-zero");
+        self.deque.make_contiguous().windows(window)
+    }
+
+    /// Returns a reference to the element at the given index.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IndexError`] with the requested index and current length
+    /// if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::{Deque, IndexError};
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+    /// assert_eq!(deque.try_get(1), Ok(&2));
+    /// assert_eq!(deque.try_get(3), Err(IndexError { index: 3, len: 3 }));
+    /// ```
+    pub fn try_get(&self, index: usize) -> Result<&T, IndexError> {
+        self.deque.get(index).ok_or(IndexError {
+            index,
+            len: self.deque.len(),
+        })
+    }
+
+    /// Overwrites the element at the given index.
+    ///
+    /// # Errors
+    ///
+    /// Returns the given value back as `Err` if the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+    /// assert_eq!(deque.set(1, 20), Ok(()));
+    /// assert_eq!(deque.get(1), Some(&20));
+    /// assert_eq!(deque.set(3, 30), Err(30));
+    /// ```
+    pub fn set(&mut self, index: usize, value: T) -> Result<(), T> {
+        self.replace(index, value).map(drop)
+    }
+
+    /// Calls the given closure with mutable access to the inner `VecDeque`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CapacityError`] if the closure left more than `maxlen` elements in the deque.
+    /// The deque is then truncated to `maxlen` by dropping elements from the back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = (vec![1, 2], 3).into();
+    /// assert_eq!(deque.try_with_inner_mut(|inner| inner.push_back(3)), Ok(()));
+    /// assert!(deque.try_with_inner_mut(|inner| inner.push_back(4)).is_err());
+    /// assert_eq!(deque.len(), 3);
+    /// ```
+    pub fn try_with_inner_mut<R, F>(&mut self, f: F) -> Result<R, CapacityError>
+    where
+        F: FnOnce(&mut VecDeque<T>) -> R,
+    {
+        let result = f(&mut self.deque);
+        let len = self.deque.len();
+        if len > self.maxlen {
+            self.deque.truncate(self.maxlen);
+            return Err(CapacityError {
+                len,
+                maxlen: self.maxlen,
+            });
+        }
+        Ok(result)
+    }
+
+    /// Splits the elements into segments separated by elements matching the predicate.
+    /// The matching elements are not included in the segments.
+    ///
+    /// This works like `slice::split`, so adjacent separators
+    /// or a separator at either end produce empty segments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 0, 2, 3, 0, 4], 6).into();
+    /// assert_eq!(deque.split_when(|&x| x == 0), vec![vec![1], vec![2, 3], vec![4]]);
+    /// ```
+    pub fn split_when<P>(&self, mut pred: P) -> Vec<Vec<T>>
+    where
+        T: Clone,
+        P: FnMut(&T) -> bool,
+    {
+        let mut segments = vec![Vec::new()];
+        for value in &self.deque {
+            if pred(value) {
+                segments.push(Vec::new());
+            } else if let Some(segment) = segments.last_mut() {
+                segment.push(value.clone());
+            }
+        }
+        segments
+    }
+
+    /// Add an element to the back of the Deque,
+    /// pushing the element returned by [`push_back`](Self::push_back), if any, onto the given `Vec`.
+    /// With the default overflow policy this is the evicted front element.
+    ///
+    /// This allows collecting evicted elements across many pushes without allocating for each call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = (vec![1, 2], 2).into();
+    /// let mut overflow = Vec::new();
+    /// deque.push_back_draining(3, &mut overflow);
+    /// deque.push_back_draining(4, &mut overflow);
+    /// assert_eq!(overflow, vec![1, 2]);
+    /// ```
+    pub fn push_back_draining(&mut self, value: T, overflow: &mut Vec<T>) {
+        overflow.extend(self.push_back(value));
+    }
+
+    /// Refills the deque by pushing clones of the elements of the slice to the back
+    /// without clearing it first, and returns the elements that did not fit.
+    ///
+    /// This is an alias of [`push_frame`](Self::push_frame),
+    /// which describes what is returned under each overflow policy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+    /// assert_eq!(deque.refill_from_slice(&[4, 5]), vec![1, 2]);
+    /// assert_eq!(deque.front(), Some(&3));
+    /// ```
+    pub fn refill_from_slice(&mut self, src: &[T]) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.push_frame(src)
+    }
+
+    /// Returns owned copies of each overlapping window of the given size, front to back.
+    /// The result is empty if the window is larger than the deque.
+    ///
+    /// The deque is made contiguous first, which may reorder the internal buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+    /// assert_eq!(deque.windows_owned(2), vec![vec![1, 2], vec![2, 3]]);
+    /// ```
+    pub fn windows_owned(&mut self, size: usize) -> Vec<Vec<T>>
+    where
+        T: Clone,
+    {
+        self.windows(size).map(<[T]>::to_vec).collect()
+    }
+
+    /// Tries to reserve capacity for at least `additional` more elements.
+    ///
+    /// Since the deque never holds more than `maxlen` elements,
+    /// `additional` is capped at `maxlen - len()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `TryReserveError` if the capacity overflows or the allocator reports a failure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = Deque::from_vec(vec![1, 2], 10_000);
+    /// assert!(deque.try_reserve(100).is_ok());
+    /// assert!(deque.capacity() >= 102);
+    /// ```
+    pub fn try_reserve(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), std::collections::TryReserveError> {
+        let additional = additional.min(self.maxlen.saturating_sub(self.deque.len()));
+        self.deque.try_reserve(additional)
+    }
+
+    /// Returns a read-only view into the deque.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2, 3], 5).into();
+    /// let view = deque.view();
+    /// assert_eq!(view.len(), 3);
+    /// assert_eq!(view.maxlen(), 5);
+    /// ```
+    #[must_use]
+    pub const fn view(&self) -> DequeView<'_, T> {
+        DequeView {
+            deque: &self.deque,
+            maxlen: self.maxlen,
+        }
+    }
+
+    /// Applies `f` to each contiguous window of the given size, front to back,
+    /// and writes the results into the given `Vec`.
+    /// The output is cleared first so its allocation can be reused.
+    ///
+    /// The deque is made contiguous first, which may reorder the internal buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+    /// let mut out = Vec::new();
+    /// deque.rolling_into(3, &mut out, |window| window.iter().max().copied());
+    /// assert_eq!(out, vec![Some(3), Some(4)]);
+    /// ```
+    pub fn rolling_into<R, F>(&mut self, window: usize, out: &mut Vec<R>, f: F)
+    where
+        F: FnMut(&[T]) -> R,
+    {
+        out.clear();
+        out.extend(self.windows(window).map(f));
+    }
+
+    /// Returns the indices of all elements matching the predicate, front to back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2, 1, 3, 1], 5).into();
+    /// assert_eq!(deque.positions(|&x| x == 1), vec![0, 2, 4]);
+    /// ```
+    pub fn positions<P>(&self, mut pred: P) -> Vec<usize>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.deque
+            .iter()
+            .enumerate()
+            .filter_map(|(index, value)| pred(value).then_some(index))
+            .collect()
+    }
+
+    /// Returns a `bool` for each element indicating whether it matches the predicate, front to back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+    /// assert_eq!(deque.mask(|&x| x % 2 == 0), vec![false, true, false, true]);
+    /// ```
+    pub fn mask<P>(&self, pred: P) -> Vec<bool>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.deque.iter().map(pred).collect()
+    }
+
+    /// Shortens the deque to at most `len` elements by dropping elements from the front,
+    /// keeping the most recent ones at the back.
+    /// Does nothing if the deque already has `len` elements or fewer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+    /// deque.truncate_front(2);
+    /// assert_eq!(deque.front(), Some(&3));
+    /// assert_eq!(deque.len(), 2);
+    /// ```
+    pub fn truncate_front(&mut self, len: usize) {
+        let excess = self.deque.len().saturating_sub(len);
+        self.deque.drain(..excess);
+    }
+
+    /// Rotates the deque to the left until the first occurrence of the given value is at the front.
+    /// Returns `false` and leaves the deque unchanged if the value is not present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+    /// assert!(deque.rotate_to_value_front(&3));
+    /// assert_eq!(deque.front(), Some(&3));
+    /// assert_eq!(deque.back(), Some(&2));
+    /// ```
+    pub fn rotate_to_value_front(&mut self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        match self.index_of(value) {
+            Some(index) => {
+                self.deque.rotate_left(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Returns a front-to-back iterator that yields each element with its distance from the back.
+    /// The back element has distance 0, so the distance tells how many elements were pushed after it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![10, 20], 3).into();
+    /// let mut iter = deque.with_index_from_back();
+    /// assert_eq!(iter.next(), Some((1, &10)));
+    /// assert_eq!(iter.next(), Some((0, &20)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn with_index_from_back(&self) -> impl Iterator<Item = (usize, &T)> {
+        let last = self.deque.len().saturating_sub(1);
+        self.deque
+            .iter()
+            .enumerate()
+            .map(move |(index, value)| (last - index, value))
+    }
+
+    /// Sets a new maximum length without evicting any elements.
+    ///
+    /// If the deque holds more than `new_maxlen` elements,
+    /// it stays temporarily overfull until the next push,
+    /// which evicts as many elements as needed, following the overflow policy,
+    /// to bring it back within the new limit.
+    /// Pops and [`normalize`](Self::normalize) also bring it back within the limit.
+    /// Under [`OverflowPolicy::RejectNew`] pushes are rejected instead,
+    /// so only pops and `normalize` do.
+    ///
+    /// While the deque is overfull, [`capacity`](Self::capacity) is at least its length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+    /// deque.set_maxlen_lazy(2);
+    /// assert_eq!(deque.len(), 4);
+    /// assert_eq!(deque.push_back(5), Some(1));
+    /// assert_eq!(deque.len(), 2);
+    /// assert_eq!(deque.front(), Some(&4));
+    /// ```
+    pub const fn set_maxlen_lazy(&mut self, new_maxlen: usize) {
+        self.maxlen = new_maxlen;
+    }
+
+    /// Decomposes the deque into the inner `VecDeque` and the maximum length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2, 3], 5).into();
+    /// let (inner, maxlen) = deque.into_parts();
+    /// assert_eq!(inner.len(), 3);
+    /// assert_eq!(maxlen, 5);
+    /// ```
+    #[must_use]
+    pub fn into_parts(self) -> (VecDeque<T>, usize) {
+        (self.deque, self.maxlen)
+    }
+
+    /// Keeps only the `n` most recently pushed elements at the back,
+    /// dropping older elements from the front.
+    ///
+    /// This is the same as [`truncate_front`](Self::truncate_front).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = (vec![1, 2, 3, 4, 5], 5).into();
+    /// deque.retain_last_n(2);
+    /// assert_eq!(deque.front(), Some(&4));
+    /// ```
+    pub fn retain_last_n(&mut self, n: usize) {
+        self.truncate_front(n);
+    }
+
+    /// Returns `true` if both deques contain the same elements
+    /// the same number of times, regardless of order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let first: Deque<i32> = (vec![1, 2, 3], 3).into();
+    /// let second: Deque<i32> = (vec![3, 2, 1], 3).into();
+    /// assert!(first.eq_ignore_order(&second));
+    /// ```
+    #[must_use]
+    pub fn eq_ignore_order(&self, other: &Self) -> bool
+    where
+        T: Eq + Hash,
+    {
+        self.deque.len() == other.deque.len() && self.histogram() == other.histogram()
+    }
+
+    /// Converts the deque into an array of exactly `N` elements, front to back.
+    ///
+    /// # Errors
+    ///
+    /// Returns the deque unchanged as `Err` if its length is not `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+    /// assert_eq!(deque.into_array::<3>(), Ok([1, 2, 3]));
+    /// ```
+    pub fn into_array<const N: usize>(self) -> Result<[T; N], Self> {
+        if self.deque.len() != N {
+            return Err(self);
+        }
+        let Self {
+            deque,
+            maxlen,
+            policy,
+            evicted,
+        } = self;
+        Vec::from(deque).try_into().map_err(|vec: Vec<T>| Self {
+            deque: VecDeque::from(vec),
+            maxlen,
+            policy,
+            evicted,
+        })
+    }
+
+    /// Returns the minimum of each contiguous window of the given size, front to back.
+    /// The result is empty if the window is larger than the deque.
+    ///
+    /// Uses a monotonic queue, so this runs in linear time regardless of the window size.
+    /// The deque is made contiguous first, which may reorder the internal buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = (vec![4, 2, 3, 1, 5], 5).into();
+    /// assert_eq!(deque.rolling_min(3), vec![2, 1, 1]);
+    /// ```
+    pub fn rolling_min(&mut self, window: usize) -> Vec<T>
+    where
+        T: Ord + Clone,
+    {
+        self.rolling_extreme(window, |candidate, value| candidate < value)
+    }
+
+    /// Returns the maximum of each contiguous window of the given size, front to back.
+    /// The result is empty if the window is larger than the deque.
+    ///
+    /// Uses a monotonic queue, so this runs in linear time regardless of the window size.
+    /// The deque is made contiguous first, which may reorder the internal buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = (vec![4, 2, 3, 1, 5], 5).into();
+    /// assert_eq!(deque.rolling_max(3), vec![4, 3, 5]);
+    /// ```
+    pub fn rolling_max(&mut self, window: usize) -> Vec<T>
+    where
+        T: Ord + Clone,
+    {
+        self.rolling_extreme(window, |candidate, value| candidate > value)
+    }
+
+    /// Returns the extreme value of each window,
+    /// where `keep` returns `true` if an earlier candidate should stay ahead of a new value.
+    fn rolling_extreme<F>(&mut self, window: usize, keep: F) -> Vec<T>
+    where
+        T: Clone,
+        F: Fn(&T, &T) -> bool,
+    {
+        assert!(window > 0, "window size must be non-zero");
+        let values = self.deque.make_contiguous();
+        let mut result = Vec::with_capacity((values.len() + 1).saturating_sub(window));
+        // Indices of the candidates for the current window, best candidate at the front
+        let mut candidates: VecDeque<usize> = VecDeque::with_capacity(window);
+        for (index, value) in values.iter().enumerate() {
+            while candidates
+                .back()
+                .is_some_and(|&candidate| !keep(&values[candidate], value))
+            {
+                candidates.pop_back();
+            }
+            candidates.push_back(index);
+            if candidates
+                .front()
+                .is_some_and(|&candidate| candidate + window <= index)
+            {
+                candidates.pop_front();
+            }
+            if index + 1 >= window {
+                if let Some(&best) = candidates.front() {
+                    result.push(values[best].clone());
+                }
+            }
+        }
+        result
+    }
+
+    /// Returns an estimate of the heap memory used by the deque in bytes.
+    ///
+    /// This is the size of the allocated buffer,
+    /// and does not include memory owned by the elements themselves,
+    /// such as the contents of a `String`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<u64> = Deque::new(8);
+    /// assert!(deque.heap_size() >= 8 * std::mem::size_of::<u64>());
+    /// ```
+    #[must_use]
+    pub fn heap_size(&self) -> usize {
+        self.deque.capacity() * std::mem::size_of::<T>()
+    }
+
+    /// Sets the maximum length to the current length of the deque.
+    ///
+    /// The deque is then full, so every subsequent push evicts an element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = (vec![1, 2, 3], 10).into();
+    /// deque.lock_maxlen();
+    /// assert_eq!(deque.maxlen(), 3);
+    /// assert_eq!(deque.push_back(4), Some(1));
+    /// ```
+    pub fn lock_maxlen(&mut self) {
+        self.maxlen = self.deque.len();
+    }
+
+    /// Pushes elements to the back while there is spare capacity,
+    /// and returns the elements that did not fit, in order.
+    ///
+    /// Unlike [`push_back`](Self::push_back), this never evicts existing elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = Deque::new(3);
+    /// assert_eq!(deque.try_push_back_all(1..=5), vec![4, 5]);
+    /// assert_eq!(deque.len(), 3);
+    /// ```
+    pub fn try_push_back_all<I>(&mut self, iter: I) -> Vec<T>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut rejected = Vec::new();
+        for value in iter {
+            if self.deque.len() < self.maxlen {
+                self.deque.push_back(value);
+            } else {
+                rejected.push(value);
+            }
+        }
+        rejected
+    }
+
+    /// Returns the index of the first element equal to the given value,
+    /// or `None` if the deque does not contain it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+    /// assert_eq!(deque.index_of(&3), Some(2));
+    /// assert_eq!(deque.index_of(&5), None);
+    /// ```
+    pub fn index_of(&self, value: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.deque.iter().position(|element| element == value)
+    }
+
+    /// Returns a string representation matching the Python `repr` of a deque,
+    /// using the `Debug` formatting of the elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2, 3], 5).into();
+    /// assert_eq!(deque.python_repr(), "deque([1, 2, 3], maxlen=5)");
+    /// ```
+    #[must_use]
+    pub fn python_repr(&self) -> String
+    where
+        T: std::fmt::Debug,
+    {
+        let items: Vec<String> = self
+            .deque
+            .iter()
+            .map(|value| format!("{value:?}"))
+            .collect();
+        format!("deque([{}], maxlen={})", items.join(", "), self.maxlen)
+    }
+
+    /// Returns the number of leading elements that are equal in both deques.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let first: Deque<i32> = (vec![1, 2, 3], 3).into();
+    /// let second: Deque<i32> = (vec![1, 2, 9], 3).into();
+    /// assert_eq!(first.common_prefix_len(&second), 2);
+    /// ```
+    #[must_use]
+    pub fn common_prefix_len(&self, other: &Self) -> usize
+    where
+        T: PartialEq,
+    {
+        self.deque
+            .iter()
+            .zip(&other.deque)
+            .take_while(|(a, b)| a == b)
+            .count()
+    }
+
+    /// Returns a new deque with the same maximum length,
+    /// containing clones of the leading elements that match the predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2, 3, 1], 4).into();
+    /// let taken = deque.take_while(|&x| x < 3);
+    /// assert_eq!(taken.len(), 2);
+    /// assert_eq!(taken.maxlen(), 4);
+    /// ```
+    #[must_use]
+    pub fn take_while<P>(&self, mut pred: P) -> Self
+    where
+        T: Clone,
+        P: FnMut(&T) -> bool,
+    {
+        Self {
+            deque: self
+                .deque
+                .iter()
+                .take_while(|value| pred(value))
+                .cloned()
+                .collect(),
+            maxlen: self.maxlen,
+            policy: self.policy,
+            evicted: 0,
+        }
+    }
+
+    /// Returns a new deque with the same maximum length,
+    /// containing clones of the elements after the leading elements that match the predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2, 3, 1], 4).into();
+    /// let skipped = deque.skip_while(|&x| x < 3);
+    /// assert_eq!(skipped.front(), Some(&3));
+    /// assert_eq!(skipped.maxlen(), 4);
+    /// ```
+    #[must_use]
+    pub fn skip_while<P>(&self, mut pred: P) -> Self
+    where
+        T: Clone,
+        P: FnMut(&T) -> bool,
+    {
+        Self {
+            deque: self
+                .deque
+                .iter()
+                .skip_while(|value| pred(value))
+                .cloned()
+                .collect(),
+            maxlen: self.maxlen,
+            policy: self.policy,
+            evicted: 0,
+        }
+    }
+
+    /// Add an element to the back of the Deque like [`push_back`](Self::push_back),
+    /// returning the evicted element, if any, as `Ok`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the given value back as `Err` if the maximum length is zero,
+    /// since such a deque can never store an element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = Deque::new(0);
+    /// assert_eq!(deque.checked_push_back(1), Err(1));
+    ///
+    /// let mut deque: Deque<i32> = Deque::new(1);
+    /// assert_eq!(deque.checked_push_back(1), Ok(None));
+    /// assert_eq!(deque.checked_push_back(2), Ok(Some(1)));
+    /// ```
+    pub fn checked_push_back(&mut self, value: T) -> Result<Option<T>, T> {
+        if self.maxlen == 0 {
+            return Err(value);
+        }
+        Ok(self.push_back(value))
+    }
+
+    /// Returns the average of each contiguous window of the given size, front to back.
+    /// The result is empty if the window is larger than the deque.
+    ///
+    /// The deque is made contiguous first, which may reorder the internal buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+    /// assert_eq!(deque.moving_average(2), vec![1.5, 2.5, 3.5]);
+    /// ```
+    pub fn moving_average(&mut self, window: usize) -> Vec<f64>
+    where
+        T: Copy + Into<f64>,
+    {
+        self.windows(window).map(mean).collect()
+    }
+
+    /// Returns the population variance of each contiguous window of the given size, front to back.
+    /// The result is empty if the window is larger than the deque.
+    ///
+    /// The deque is made contiguous first, which may reorder the internal buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = (vec![1, 3, 5], 3).into();
+    /// assert_eq!(deque.rolling_variance(2), vec![1.0, 1.0]);
+    /// ```
+    pub fn rolling_variance(&mut self, window: usize) -> Vec<f64>
+    where
+        T: Copy + Into<f64>,
+    {
+        self.windows(window).map(variance).collect()
+    }
+
+    /// Returns the population standard deviation of each contiguous window of the given size,
+    /// front to back.
+    /// The result is empty if the window is larger than the deque.
+    ///
+    /// The deque is made contiguous first, which may reorder the internal buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = (vec![1, 3, 7], 3).into();
+    /// assert_eq!(deque.rolling_std(2), vec![1.0, 2.0]);
+    /// ```
+    pub fn rolling_std(&mut self, window: usize) -> Vec<f64>
+    where
+        T: Copy + Into<f64>,
+    {
+        self.windows(window)
+            .map(|values| variance(values).sqrt())
+            .collect()
+    }
+
+    /// Returns an iterator over non-overlapping chunks of exactly the given size, front to back.
+    /// Trailing elements that do not fill a whole chunk are left out.
+    ///
+    /// The deque is made contiguous first, which may reorder the internal buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = (vec![1, 2, 3, 4, 5], 5).into();
+    /// let mut chunks = deque.chunks_exact(2);
+    /// assert_eq!(chunks.next(), Some(&[1, 2][..]));
+    /// assert_eq!(chunks.next(), Some(&[3, 4][..]));
+    /// assert_eq!(chunks.next(), None);
+    /// ```
+    pub fn chunks_exact(&mut self, size: usize) -> std::slice::ChunksExact<'_, T> {
+        assert!(size > 0, "chunk size must be non-zero");
+        self.deque.make_contiguous().chunks_exact(size)
+    }
+
+    /// Add an element to the back of a full Deque and return the evicted front element.
+    ///
+    /// This is meant for ring buffers that are always full,
+    /// where the evicted element is guaranteed to exist.
+    /// The front element is always evicted regardless of the overflow policy.
+    /// A deque left overfull by [`set_maxlen_lazy`](Self::set_maxlen_lazy)
+    /// evicts as many front elements as needed, and the first one is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the deque is not full, or if the maximum length is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+    /// assert_eq!(deque.cycle_push(4), 1);
+    /// assert_eq!(deque.cycle_push(5), 2);
+    /// ```
+    pub fn cycle_push(&mut self, value: T) -> T {
+        assert!(
+            self.maxlen > 0 && self.deque.len() >= self.maxlen,
+            "cycle_push requires a full deque"
+        );
+        // A full deque with a non-zero maximum length always has a front element to evict
+        let evicted = self
+            .evict_until_room(true)
+            .expect("full deque has a front element");
+        self.deque.push_back(value);
+        evicted
+    }
+
+    /// Pops elements from the front while their key is less than `min_key`,
+    /// and returns the expired elements in pop order.
+    ///
+    /// Assumes the keys are non-decreasing from front to back,
+    /// such as timestamps of elements pushed to the back over time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<(u64, &str)> = (vec![(1, "a"), (5, "b"), (9, "c")], 3).into();
+    /// let expired = deque.expire(|&(time, _)| time, 5);
+    /// assert_eq!(expired, vec![(1, "a")]);
+    /// assert_eq!(deque.len(), 2);
+    /// ```
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn expire<K, F>(&mut self, mut key_of: F, min_key: K) -> Vec<T>
+    where
+        K: PartialOrd,
+        F: FnMut(&T) -> K,
+    {
+        let mut expired = Vec::new();
+        while self
+            .deque
+            .front()
+            .is_some_and(|front| key_of(front) < min_key)
+        {
+            expired.extend(self.deque.pop_front());
+        }
+        expired
+    }
+
+    /// Pops elements from the front until the deque is within its maximum length,
+    /// and returns the evicted elements in pop order.
+    ///
+    /// This only does something for a deque left overfull by
+    /// [`set_maxlen_lazy`](Self::set_maxlen_lazy).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+    /// deque.set_maxlen_lazy(2);
+    /// assert_eq!(deque.normalize(), vec![1, 2]);
+    /// assert_eq!(deque.len(), 2);
+    /// ```
+    pub fn normalize(&mut self) -> Vec<T> {
+        let excess = self.deque.len().saturating_sub(self.maxlen);
+        let evicted = self.pop_front_n(excess);
+        self.evicted += evicted.len() as u64;
+        evicted
+    }
+
+    /// Returns an iterator over each pair of adjacent elements together with their indices.
+    /// Empty and single-element deques yield nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![10, 20], 3).into();
+    /// let mut pairs = deque.pairwise_indexed();
+    /// assert_eq!(pairs.next(), Some(((0, &10), (1, &20))));
+    /// assert_eq!(pairs.next(), None);
+    /// ```
+    pub fn pairwise_indexed(&self) -> impl Iterator<Item = ((usize, &T), (usize, &T))> {
+        self.iter_indexed().zip(self.iter_indexed().skip(1))
+    }
+
+    /// Returns the number of consecutive elements at the front that match the predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![0, 0, 1, 2, 0], 5).into();
+    /// assert_eq!(deque.count_leading(|&x| x == 0), 2);
+    /// ```
+    pub fn count_leading<P>(&self, mut pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.deque.iter().take_while(|value| pred(value)).count()
+    }
+
+    /// Returns the number of consecutive elements at the back that match the predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![0, 0, 1, 2, 0], 5).into();
+    /// assert_eq!(deque.count_trailing(|&x| x == 0), 1);
+    /// ```
+    pub fn count_trailing<P>(&self, mut pred: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.deque
+            .iter()
+            .rev()
+            .take_while(|value| pred(value))
+            .count()
+    }
+
+    /// Returns the front element and references to the rest of the elements,
+    /// or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+    /// assert_eq!(deque.split_first(), Some((&1, vec![&2, &3])));
+    /// ```
+    #[must_use]
+    pub fn split_first(&self) -> Option<(&T, Vec<&T>)> {
+        let first = self.deque.front()?;
+        let rest = self.deque.iter().skip(1).collect();
+        Some((first, rest))
+    }
+
+    /// Returns the back element and references to the rest of the elements, front to back,
+    /// or `None` if the deque is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+    /// assert_eq!(deque.split_last(), Some((&3, vec![&1, &2])));
+    /// ```
+    #[must_use]
+    pub fn split_last(&self) -> Option<(&T, Vec<&T>)> {
+        let last = self.deque.back()?;
+        let rest = self.iter_except_last().collect();
+        Some((last, rest))
+    }
+
+    /// Inserts clones of the elements of the slice at the front,
+    /// so that the deque starts with the slice followed by the existing elements.
+    /// Elements are evicted from the back as needed and returned in eviction order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = (vec![2, 3], 3).into();
+    /// assert_eq!(deque.prepend_from_slice(&[0, 1]), vec![3]);
+    /// assert_eq!(deque.front(), Some(&0));
+    /// assert_eq!(deque.back(), Some(&2));
+    /// ```
+    pub fn prepend_from_slice(&mut self, src: &[T]) -> Vec<T>
+    where
+        T: Clone,
+    {
+        src.iter()
+            .rev()
+            .filter_map(|value| self.push_front(value.clone()))
+            .collect()
+    }
+
+    /// Returns `true` if both deques contain the same elements and have the same maximum length.
+    ///
+    /// Unlike `==`, which only compares the elements, this also compares `maxlen`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let first: Deque<i32> = (vec![1, 2], 2).into();
+    /// let second: Deque<i32> = (vec![1, 2], 5).into();
+    /// assert!(first == second);
+    /// assert!(!first.eq_exact(&second));
+    /// ```
+    #[must_use]
+    pub fn eq_exact(&self, other: &Self) -> bool
+    where
+        T: PartialEq,
+    {
+        self.maxlen == other.maxlen && self.deque == other.deque
+    }
+
+    /// Returns a front-to-back iterator that knows its exact length.
+    ///
+    /// This is the same as [`iter`](Self::iter),
+    /// but the return type only promises an `ExactSizeIterator`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2, 3], 5).into();
+    /// assert_eq!(deque.iter_counted().len(), 3);
+    /// ```
+    #[must_use]
+    pub fn iter_counted(&self) -> impl ExactSizeIterator<Item = &T> {
+        self.deque.iter()
+    }
+
+    /// Returns references to the last `window` elements, front to back,
+    /// or `None` if the deque has fewer than `window` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+    /// assert_eq!(deque.last_window(2), Some(vec![&3, &4]));
+    /// assert_eq!(deque.last_window(5), None);
+    /// ```
+    #[must_use]
+    pub fn last_window(&self, window: usize) -> Option<Vec<&T>> {
+        let start = self.deque.len().checked_sub(window)?;
+        Some(self.deque.range(start..).collect())
+    }
+
+    /// Returns `true` if the elements read the same front to back and back to front.
+    /// Empty and single-element deques are palindromes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2, 1], 3).into();
+    /// assert!(deque.is_palindrome());
+    /// ```
+    #[must_use]
+    pub fn is_palindrome(&self) -> bool
+    where
+        T: PartialEq,
+    {
+        self.deque
+            .iter()
+            .zip(self.deque.iter().rev())
+            .take(self.deque.len() / 2)
+            .all(|(front, back)| front == back)
+    }
+
+    /// Converts a full deque into a `Vec` of its elements, front to back.
+    ///
+    /// # Errors
+    ///
+    /// Returns the deque unchanged as `Err` if it is not full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+    /// assert_eq!(deque.into_full_vec(), Ok(vec![1, 2, 3]));
+    /// ```
+    pub fn into_full_vec(self) -> Result<Vec<T>, Self> {
+        if self.deque.len() == self.maxlen {
+            Ok(Vec::from(self.deque))
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Retains only the elements matching the predicate, front to back,
+    /// and returns the number of elements removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = (vec![1, 2, 3, 4, 5], 5).into();
+    /// assert_eq!(deque.retain_counting(|&x| x % 2 == 0), 3);
+    /// assert_eq!(deque.len(), 2);
+    /// ```
+    pub fn retain_counting<F>(&mut self, f: F) -> usize
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let len = self.deque.len();
+        self.deque.retain(f);
+        len - self.deque.len()
+    }
+
+    /// Merges two sorted deques into a new sorted deque with the given maximum length,
+    /// keeping the first `maxlen` elements of the merged result.
+    /// Equal elements from this deque come before those from `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let first: Deque<i32> = (vec![1, 3, 5], 3).into();
+    /// let second: Deque<i32> = (vec![2, 4, 6], 3).into();
+    /// let merged = first.merge_sorted(&second, 4);
+    /// assert_eq!(merged.len(), 4);
+    /// assert_eq!(merged.back(), Some(&4));
+    /// ```
+    #[must_use]
+    pub fn merge_sorted(&self, other: &Self, maxlen: usize) -> Self
+    where
+        T: Ord + Clone,
+    {
+        let mut merged = Self::new(maxlen);
+        let mut left = self.deque.iter().peekable();
+        let mut right = other.deque.iter().peekable();
+        while merged.len() < maxlen {
+            let next = match (left.peek(), right.peek()) {
+                (Some(a), Some(b)) if b < a => right.next(),
+                (Some(_), _) => left.next(),
+                (None, _) => right.next(),
+            };
+            match next {
+                Some(value) => merged.deque.push_back(value.clone()),
+                None => break,
+            }
+        }
+        merged
+    }
+
+    /// Returns an iterator that repeats the elements front to back endlessly.
+    /// An empty deque yields nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2], 2).into();
+    /// let cycled: Vec<&i32> = deque.cycle().take(3).collect();
+    /// assert_eq!(cycled, vec![&1, &2, &1]);
+    /// ```
+    pub fn cycle(&self) -> impl Iterator<Item = &T> {
+        self.deque.iter().cycle()
+    }
+
+    /// Folds each contiguous window of the given size with `f`, starting from `init`,
+    /// and returns the results front to back.
+    /// The result is empty if the window is larger than the deque.
+    ///
+    /// The deque is made contiguous first, which may reorder the internal buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+    /// assert_eq!(deque.window_fold(2, 1, |acc, x| acc * x), vec![2, 6, 12]);
+    /// ```
+    pub fn window_fold<B, F>(&mut self, window: usize, init: B, mut f: F) -> Vec<B>
+    where
+        B: Clone,
+        F: FnMut(B, &T) -> B,
+    {
+        self.windows(window)
+            .map(|values| values.iter().fold(init.clone(), &mut f))
+            .collect()
+    }
+
+    /// Returns the first index at which the running sum of the elements exceeds the threshold,
+    /// or `None` if the total never exceeds it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+    /// assert_eq!(deque.prefix_sum_threshold(4), Some(2));
+    /// ```
+    pub fn prefix_sum_threshold(&self, threshold: T) -> Option<usize>
+    where
+        T: Copy + PartialOrd + std::iter::Sum + std::ops::Add<Output = T>,
+    {
+        let mut sum: T = std::iter::empty().sum();
+        self.deque.iter().position(|&value| {
+            sum = sum + value;
+            sum > threshold
+        })
+    }
+
+    /// Returns the lengths of the two slices returned by [`as_slices`](Self::as_slices).
+    ///
+    /// A nonzero second length means the elements wrap around the end of the internal buffer,
+    /// and [`align_to_front`](Self::align_to_front) could make them contiguous.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+    /// assert_eq!(deque.slice_lengths(), (3, 0));
+    /// ```
+    #[must_use]
+    pub fn slice_lengths(&self) -> (usize, usize) {
+        let (front, back) = self.deque.as_slices();
+        (front.len(), back.len())
+    }
+
+    /// Pops elements from the back while they match the predicate,
+    /// and returns the popped elements in pop order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = (vec![1, 2, 5, 6], 4).into();
+    /// assert_eq!(deque.drain_back_while(|&x| x > 4), vec![6, 5]);
+    /// assert_eq!(deque.len(), 2);
+    /// ```
+    pub fn drain_back_while<P>(&mut self, mut pred: P) -> Vec<T>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let mut drained = Vec::new();
+        while self.deque.back().is_some_and(&mut pred) {
+            drained.extend(self.deque.pop_back());
+        }
+        drained
+    }
+
+    /// Pops elements from the front while they match the predicate,
+    /// and returns the popped elements in pop order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = (vec![1, 2, 5, 6], 4).into();
+    /// assert_eq!(deque.drain_front_while(|&x| x < 4), vec![1, 2]);
+    /// assert_eq!(deque.len(), 2);
+    /// ```
+    pub fn drain_front_while<P>(&mut self, mut pred: P) -> Vec<T>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let mut drained = Vec::new();
+        while self.deque.front().is_some_and(&mut pred) {
+            drained.extend(self.deque.pop_front());
+        }
+        drained
+    }
+
+    /// Returns a reference to the element at the given index,
+    /// or `None` if the index is out of bounds.
+    ///
+    /// This is the same as [`get`](Self::get).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+    /// assert_eq!(deque.at(1), Some(&2));
+    /// assert_eq!(deque.at(3), None);
+    /// ```
+    #[must_use]
+    pub fn at(&self, index: usize) -> Option<&T> {
+        self.get(index)
+    }
+
+    /// Returns a mutable reference to the element at the given index,
+    /// or `None` if the index is out of bounds.
+    ///
+    /// This is the same as [`get_mut`](Self::get_mut).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+    /// if let Some(value) = deque.at_mut(1) {
+    ///     *value = 20;
+    /// }
+    /// assert_eq!(deque.at(1), Some(&20));
+    /// ```
+    pub fn at_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.get_mut(index)
+    }
+
+    /// Returns the difference between each element and the one before it, front to back.
+    /// Empty and single-element deques return an empty `Vec`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 3, 6, 10], 4).into();
+    /// assert_eq!(deque.diffs(), vec![2, 3, 4]);
+    /// ```
+    #[must_use]
+    pub fn diffs(&self) -> Vec<T>
+    where
+        T: Copy + std::ops::Sub<Output = T>,
+    {
+        self.pairwise().map(|(&prev, &next)| next - prev).collect()
+    }
+
+    /// Returns a reference to the `n`th element counting from the back,
+    /// where `from_back(0)` is the back element,
+    /// or `None` if `n` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+    /// assert_eq!(deque.from_back(0), Some(&3));
+    /// assert_eq!(deque.from_back(2), Some(&1));
+    /// assert_eq!(deque.from_back(3), None);
+    /// ```
+    #[must_use]
+    pub fn from_back(&self, n: usize) -> Option<&T> {
+        let index = self.deque.len().checked_sub(n)?.checked_sub(1)?;
+        self.deque.get(index)
+    }
+
+    /// Inserts an element into a sorted deque, keeping it sorted.
+    ///
+    /// If the deque is full, the smallest element is evicted and returned,
+    /// so the deque keeps the largest `maxlen` elements seen.
+    /// When the new element itself would be the smallest, it is returned instead.
+    /// Otherwise, `None` is returned.
+    ///
+    /// The deque must already be sorted in ascending order,
+    /// for example by only adding elements with this method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = (vec![1, 3, 5], 3).into();
+    /// assert_eq!(deque.insert_sorted(4), Some(1));
+    /// assert_eq!(deque.front(), Some(&3));
+    /// assert_eq!(deque.get(1), Some(&4));
+    /// ```
+    pub fn insert_sorted(&mut self, value: T) -> Option<T>
+    where
+        T: Ord,
+    {
+        let evicted = if self.deque.len() >= self.maxlen {
+            match self.deque.front() {
+                Some(front) if *front < value => self.deque.pop_front(),
+                _ => return Some(value),
+            }
+        } else {
+            None
+        };
+        if evicted.is_some() {
+            self.evicted += 1;
+        }
+        let index = self.deque.partition_point(|element| *element <= value);
+        self.deque.insert(index, value);
+        evicted
+    }
+
+    /// Clears all elements from the deque and releases as much of its allocation as possible.
+    ///
+    /// Unlike [`clear`](Self::clear), which keeps the allocation for reuse,
+    /// this is meant for long-lived deques that are periodically emptied.
+    /// Shrinking is a best-effort request to the allocator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = Deque::new(100);
+    /// deque.push_back(1);
+    /// deque.clear_and_shrink();
+    /// assert!(deque.is_empty());
+    /// assert!(deque.capacity() < 100);
+    /// ```
+    pub fn clear_and_shrink(&mut self) {
+        self.deque.clear();
+        self.deque.shrink_to_fit();
+    }
+
+    /// Returns references to the first `n` elements, front to back.
+    /// If `n` is larger than the length of the deque, all elements are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+    /// assert_eq!(deque.peek_front_n(2), vec![&1, &2]);
+    /// ```
+    #[must_use]
+    pub fn peek_front_n(&self, n: usize) -> Vec<&T> {
+        self.deque.iter().take(n).collect()
+    }
+
+    /// Returns references to the last `n` elements, front to back.
+    /// If `n` is larger than the length of the deque, all elements are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+    /// assert_eq!(deque.peek_back_n(2), vec![&3, &4]);
+    /// ```
+    #[must_use]
+    pub fn peek_back_n(&self, n: usize) -> Vec<&T> {
+        let start = self.deque.len().saturating_sub(n);
+        self.deque.range(start..).collect()
+    }
+
+    /// Returns a new deque with a maximum length of `n`,
+    /// containing clones of the first `n` elements.
+    /// If `n` is larger than the length of the deque, all elements are cloned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+    /// let chunk = deque.front_chunk(2);
+    /// assert_eq!(chunk, Deque::from_vec(vec![1, 2], 2));
+    /// assert_eq!(chunk.maxlen(), 2);
+    /// ```
+    #[must_use]
+    pub fn front_chunk(&self, n: usize) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            deque: self.deque.iter().take(n).cloned().collect(),
+            maxlen: n,
+            policy: self.policy,
+            evicted: 0,
+        }
+    }
+
+    /// Returns a new deque with a maximum length of `n`,
+    /// containing clones of the last `n` elements.
+    /// If `n` is larger than the length of the deque, all elements are cloned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+    /// let chunk = deque.back_chunk(2);
+    /// assert_eq!(chunk, Deque::from_vec(vec![3, 4], 2));
+    /// assert_eq!(chunk.maxlen(), 2);
+    /// ```
+    #[must_use]
+    pub fn back_chunk(&self, n: usize) -> Self
+    where
+        T: Clone,
+    {
+        let start = self.deque.len().saturating_sub(n);
+        Self {
+            deque: self.deque.range(start..).cloned().collect(),
+            maxlen: n,
+            policy: self.policy,
+            evicted: 0,
+        }
+    }
+
+    /// Overwrites the element at `logical_index % maxlen`,
+    /// treating the deque as a classic fixed ring buffer addressed by an ever-increasing index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the deque is not full, or if the maximum length is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<i32> = (vec![0, 0, 0], 3).into();
+    /// deque.write_wrapping(4, 7);
+    /// assert_eq!(deque.get(1), Some(&7));
+    /// ```
+    pub fn write_wrapping(&mut self, logical_index: usize, value: T) {
+        assert!(
+            self.maxlen > 0 && self.deque.len() == self.maxlen,
+            "write_wrapping requires a full deque"
+        );
+        self.deque[logical_index % self.maxlen] = value;
+    }
+
+    /// Calls `f` with the index and a mutable reference to each element, front to back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<usize> = (vec![10, 10, 10], 3).into();
+    /// deque.apply_indexed(|index, value| *value += index);
+    /// assert_eq!(deque, Deque::from_vec(vec![10, 11, 12], 3));
+    /// ```
+    pub fn apply_indexed<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize, &mut T),
+    {
+        for (index, value) in self.deque.iter_mut().enumerate() {
+            f(index, value);
+        }
+    }
+}
+
+/// Returns the arithmetic mean of a non-empty slice.
+#[allow(clippy::cast_precision_loss)]
+fn mean<T: Copy + Into<f64>>(values: &[T]) -> f64 {
+    values.iter().map(|&value| value.into()).sum::<f64>() / values.len() as f64
+}
+
+/// Returns the population variance of a non-empty slice.
+#[allow(clippy::cast_precision_loss)]
+fn variance<T: Copy + Into<f64>>(values: &[T]) -> f64 {
+    let average = mean(values);
+    values
+        .iter()
+        .map(|&value| (value.into() - average).powi(2))
+        .sum::<f64>()
+        / values.len() as f64
+}
+
+// Implement flatten for deques of vectors.
+impl<T> Deque<Vec<T>> {
+    /// Concatenates all inner vectors front to back into a single `Vec`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<Vec<i32>> = (vec![vec![1, 2], vec![3]], 2).into();
+    /// assert_eq!(deque.flatten(), vec![1, 2, 3]);
+    /// ```
+    #[must_use]
+    pub fn flatten(self) -> Vec<T> {
+        self.deque.into_iter().flatten().collect()
+    }
+}
+
+impl Deque<u8> {
+    /// Returns the stored bytes as a pair of slices, front to back.
+    ///
+    /// This is the same as [`Deque::as_slices`], named for byte buffers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<u8> = (vec![1, 2, 3], 3).into();
+    /// assert_eq!(deque.as_byte_slices(), (&[1, 2, 3][..], &[][..]));
+    /// ```
+    #[must_use]
+    pub fn as_byte_slices(&self) -> (&[u8], &[u8]) {
+        self.deque.as_slices()
+    }
+
+    /// Copies the stored bytes front to back into a contiguous `Vec<u8>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let mut deque: Deque<u8> = Deque::new(3);
+    /// deque.extend(b"abcd");
+    /// assert_eq!(deque.to_bytes(), b"bcd".to_vec());
+    /// ```
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.deque.iter().copied().collect()
+    }
+}
+
+impl<'a, T> DequeView<'a, T> {
+    /// Returns the maximum length of the viewed deque.
+    #[must_use]
+    pub const fn maxlen(&self) -> usize {
+        self.maxlen
+    }
+
+    /// Returns the number of elements in the viewed deque.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.deque.len()
+    }
+
+    /// Returns whether the viewed deque is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.deque.is_empty()
+    }
+
+    /// Returns a reference to the element at the given index.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        self.deque.get(index)
+    }
+
+    /// Provides a reference to the front element, or `None` if the deque is empty.
+    #[must_use]
+    pub fn front(&self) -> Option<&'a T> {
+        self.deque.front()
+    }
+
+    /// Provides a reference to the back element, or `None` if the deque is empty.
+    #[must_use]
+    pub fn back(&self) -> Option<&'a T> {
+        self.deque.back()
+    }
+
+    /// Returns a front-to-back iterator.
+    #[must_use]
+    pub fn iter(&self) -> std::collections::vec_deque::Iter<'a, T> {
+        self.deque.iter()
+    }
+}
+
+impl<T> Clone for DequeView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for DequeView<'_, T> {}
+
+impl<'a, T> IntoIterator for DequeView<'a, T> {
+    type Item = &'a T;
+    type IntoIter = std::collections::vec_deque::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.deque.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &DequeView<'a, T> {
+    type Item = &'a T;
+    type IntoIter = std::collections::vec_deque::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.deque.iter()
+    }
+}
+
+// Implement From for single value.
+impl<T> From<(T, usize)> for Deque<T> {
+    /// Creates a new Deque from a single value and a maximum length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (1, 3).into();
+    /// assert_eq!(deque.len(), 1);
+    /// assert_eq!(deque.get(0), Some(&1));
+    /// ```
+    fn from((value, maxlen): (T, usize)) -> Self {
+        Self {
+            deque: VecDeque::from([value]),
+            maxlen,
+            policy: OverflowPolicy::default(),
+            evicted: 0,
+        }
+    }
+}
+
+// Implement From for arrays.
+impl<T, const N: usize> From<([T; N], usize)> for Deque<T> {
+    /// Creates a new Deque from an array and a maximum length.
+    /// If the array is larger than the maximum length,
+    /// only the first `maxlen` elements are used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = ([1, 2, 3], 16).into();
+    /// assert_eq!(deque.len(), 3);
+    /// assert_eq!(deque.maxlen(), 16);
+    /// assert_eq!(deque.get(0), Some(&1));
+    /// assert_eq!(deque.get(2), Some(&3));
+    ///
+    /// let deque: Deque<f64> = ([1.0, 2.0, 3.0], 2).into();
+    /// assert_eq!(deque.len(), 2);
+    /// assert_eq!(deque.maxlen(), 2);
+    /// assert_eq!(deque.get(0), Some(&1.0));
+    /// assert_eq!(deque.get(1), Some(&2.0));
+    /// ```
+    fn from((array, maxlen): ([T; N], usize)) -> Self {
+        let deque = if N > maxlen {
+            // If the array size exceeds maxlen, take only the first `maxlen` elements.
+            VecDeque::from(array.into_iter().take(maxlen).collect::<Vec<_>>())
+        } else {
+            VecDeque::from(array)
+        };
+        Self {
+            deque,
+            maxlen,
+            policy: OverflowPolicy::default(),
+            evicted: 0,
+        }
+    }
+}
+
+// Implement From for Vec.
+impl<T> From<(Vec<T>, usize)> for Deque<T> {
+    /// Creates a new Deque from a Vec and a maximum length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+    /// assert_eq!(deque.len(), 3);
+    /// assert_eq!(deque.get(0), Some(&1));
+    /// assert_eq!(deque.get(2), Some(&3));
+    /// ```
+    fn from((mut vec, maxlen): (Vec<T>, usize)) -> Self {
+        vec.truncate(maxlen);
+        Self {
+            deque: VecDeque::from(vec),
+            maxlen,
+            policy: OverflowPolicy::default(),
+            evicted: 0,
+        }
+    }
+}
+
+// Implement From for slices.
+impl<T: Clone> From<(&[T], usize)> for Deque<T> {
+    /// Creates a new Deque from a slice and a maximum length.
+    /// If the slice is larger than the maximum length,
+    /// only the first `maxlen` elements are cloned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_deque::Deque;
+    ///
+    /// let deque: Deque<i32> = (&[1, 2, 3][..], 2).into();
+    /// assert_eq!(deque.len(), 2);
+    /// assert_eq!(deque.get(0), Some(&1));
+    /// assert_eq!(deque.get(1), Some(&2));
+    /// ```
+    fn from((slice, maxlen): (&[T], usize)) -> Self {
+        Self {
+            deque: slice.iter().take(maxlen).cloned().collect(),
+            maxlen,
+            policy: OverflowPolicy::default(),
+            evicted: 0,
+        }
+    }
+}
+
+// Implement From for VecDeque.
+impl<T> From<(VecDeque<T>, usize)> for Deque<T> {
+    /// Creates a new Deque from a `VecDeque` and a maximum length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::VecDeque;
+    /// use fixed_deque::Deque;
+    ///
+    /// let vec_deque: VecDeque<i32> = VecDeque::from(vec![1, 2, 3]);
+    /// let deque: Deque<i32> = (vec_deque, 3).into();
+    /// assert_eq!(deque.len(), 3);
+    /// assert_eq!(deque.get(0), Some(&1));
+    /// ```
+    fn from((mut deque, maxlen): (VecDeque<T>, usize)) -> Self {
+        deque.truncate(maxlen);
+        Self {
+            deque,
+            maxlen,
+            policy: OverflowPolicy::default(),
+            evicted: 0,
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for Deque<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.deque == other.deque
+    }
+}
+
+impl<T: PartialEq> PartialEq<VecDeque<T>> for Deque<T> {
+    fn eq(&self, other: &VecDeque<T>) -> bool {
+        &self.deque == other
+    }
+}
+
+impl<T: PartialEq> PartialEq<Deque<T>> for VecDeque<T> {
+    fn eq(&self, other: &Deque<T>) -> bool {
+        self == &other.deque
+    }
+}
+
+impl<T: Eq> Eq for Deque<T> {}
+
+impl<T> Index<usize> for Deque<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.deque[index]
+    }
+}
+
+impl<T> IndexMut<usize> for Deque<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.deque[index]
+    }
+}
+
+// Implement IntoIterator for owned Deque
+impl<T> IntoIterator for Deque<T> {
+    type Item = T;
+    type IntoIter = std::collections::vec_deque::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.deque.into_iter()
+    }
+}
+
+// Implement IntoIterator for Deque references
+impl<'a, T> IntoIterator for &'a Deque<T> {
+    type Item = &'a T;
+    type IntoIter = std::collections::vec_deque::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.deque.iter()
+    }
+}
+
+// Implement IntoIterator for mutable Deque references
+impl<'a, T> IntoIterator for &'a mut Deque<T> {
+    type Item = &'a mut T;
+    type IntoIter = std::collections::vec_deque::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.deque.iter_mut()
+    }
+}
+
+// Implement FromIterator to create Deque from an iterator
+impl<T> FromIterator<T> for Deque<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let deque: VecDeque<T> = iter.into_iter().collect();
+        let maxlen = deque.len();
+        Self::from_vec_deque(deque, maxlen)
+    }
+}
+
+// Implement Extend to push elements from an iterator to the back, evicting from the front
+impl<T> Extend<T> for Deque<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+// Implement Extend for references to copyable elements
+impl<'a, T: Copy + 'a> Extend<&'a T> for Deque<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+// Implement Write to push bytes to the back according to the overflow policy.
+// Only the bytes that were actually accepted are reported as written.
+#[cfg(feature = "io")]
+impl std::io::Write for Deque<u8> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let accepted = match self.policy {
+            OverflowPolicy::RejectNew => {
+                buf.len().min(self.maxlen.saturating_sub(self.deque.len()))
+            }
+            // A zero length deque can never store a byte
+            _ if self.maxlen == 0 => 0,
+            OverflowPolicy::EvictOpposite | OverflowPolicy::EvictSameEnd => buf.len(),
+        };
+        self.extend(&buf[..accepted]);
+        Ok(accepted)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+// Implement Read to pop bytes from the front
+#[cfg(feature = "io")]
+impl std::io::Read for Deque<u8> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let count = buf.len().min(self.deque.len());
+        for (dst, src) in buf.iter_mut().zip(self.deque.drain(..count)) {
+            *dst = src;
+        }
+        Ok(count)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Serialize> Serialize for Deque<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.deque.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Deque<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let visitor = DequeVisitor(std::marker::PhantomData);
+        // Only self-describing formats can tell a map apart from a sequence
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(visitor)
+        } else {
+            deserializer.deserialize_seq(visitor)
+        }
+    }
+}
+
+/// Deserializes a Deque from either a bare sequence of elements,
+/// in which case the maximum length is the number of elements,
+/// or, in human readable formats, from a map with `maxlen` and `items` fields.
+#[cfg(feature = "serde")]
+struct DequeVisitor<T>(std::marker::PhantomData<T>);
+
+#[cfg(feature = "serde")]
+impl<'de, T: Deserialize<'de>> serde::de::Visitor<'de> for DequeVisitor<T> {
+    type Value = Deque<T>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        formatter.write_str("a sequence or a map with `maxlen` and `items`")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let mut deque = VecDeque::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(value) = seq.next_element()? {
+            deque.push_back(value);
+        }
+        let maxlen = deque.len();
+        Ok(Deque::from_vec_deque(deque, maxlen))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        use serde::de::Error;
+
+        let mut maxlen: Option<usize> = None;
+        let mut items: Option<VecDeque<T>> = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "maxlen" if maxlen.is_some() => return Err(Error::duplicate_field("maxlen")),
+                "maxlen" => maxlen = Some(map.next_value()?),
+                "items" if items.is_some() => return Err(Error::duplicate_field("items")),
+                "items" => items = Some(map.next_value()?),
+                other => return Err(Error::unknown_field(other, &["maxlen", "items"])),
+            }
+        }
+        let maxlen = maxlen.ok_or_else(|| Error::missing_field("maxlen"))?;
+        let items = items.ok_or_else(|| Error::missing_field("items"))?;
+        Ok(Deque::from_vec_deque(items, maxlen))
+    }
+}
+
+#[cfg(test)]
+mod initialization_tests {
+    use super::{Capped, Deque};
+    use std::collections::VecDeque;
+
+    #[test]
+    fn test_initialization() {
+        let mut deque: Deque<i32> = Deque::new(1024);
+        assert_eq!(deque.len(), 0);
+        assert_eq!(deque.maxlen(), 1024);
+
+        deque = Deque::from(1, 128);
+        assert_eq!(deque.len(), 1);
+        assert_eq!(deque.maxlen(), 128);
+
+        let deque = Deque::from_vec(vec![1, 2, 3, 4, 5], 5);
+        assert_eq!(deque.len(), 5);
+        assert_eq!(deque.maxlen(), 5);
+
+        let vec_deque = VecDeque::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        let deque = Deque::from_vec_deque(vec_deque, 100);
+        assert_eq!(deque.len(), 10);
+        assert_eq!(deque.maxlen(), 100);
+    }
+
+    #[test]
+    fn test_initialization_with_into() {
+        let deque: Deque<&str> = ("a", 1024).into();
+        assert_eq!(deque.len(), 1);
+        assert_eq!(deque.maxlen(), 1024);
+
+        let mut deque: Deque<usize> = ([1, 2, 3, 4, 5, 6, 7, 8, 9, 10], 5).into();
+        assert_eq!(deque.len(), 5);
+        assert_eq!(deque.maxlen(), 5);
+
+        let vec_deque = VecDeque::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        deque = (vec_deque, 100).into();
+        assert_eq!(deque.len(), 10);
+        assert_eq!(deque.maxlen(), 100);
+    }
+
+    #[test]
+    fn test_initialization_from_longer_than_maxlen() {
+        let deque = Deque::from_vec(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10], 5);
+        assert_eq!(deque.len(), 5);
+        assert_eq!(deque.maxlen(), 5);
+
+        let vec_deque = VecDeque::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        let deque = Deque::from_vec_deque(vec_deque, 8);
+        assert_eq!(deque.len(), 8);
+        assert_eq!(deque.maxlen(), 8);
+
+        let deque: Deque<i32> = ([1, 2, 3, 4, 5, 6, 7, 8, 9, 10], 5).into();
+        assert_eq!(deque.len(), 5);
+        assert_eq!(deque.maxlen(), 5);
+
+        let deque: Deque<i32> = (vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10], 6).into();
+        assert_eq!(deque.len(), 6);
+        assert_eq!(deque.maxlen(), 6);
+
+        let vec_deque = VecDeque::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+        let deque: Deque<i32> = (vec_deque, 8).into();
+        assert_eq!(deque.len(), 8);
+        assert_eq!(deque.maxlen(), 8);
+        assert_eq!(deque.front(), Some(&1));
+        assert_eq!(deque.back(), Some(&8));
+    }
+
+    #[test]
+    fn test_initialization_from_fn() {
+        let deque: Deque<usize> = Deque::from_fn(5, 3, |i| i * 10);
+        assert_eq!(deque, VecDeque::from([0, 10, 20]));
+        assert_eq!(deque.maxlen(), 5);
+
+        let deque: Deque<usize> = Deque::from_fn(2, 10, |i| i);
+        assert_eq!(deque, VecDeque::from([0, 1]));
+        assert_eq!(deque.maxlen(), 2);
+    }
+
+    #[test]
+    fn test_initialization_repeat() {
+        let deque: Deque<i32> = Deque::repeat(0, 10, 4);
+        assert_eq!(deque, VecDeque::from([0, 0, 0, 0]));
+        assert_eq!(deque.maxlen(), 4);
+
+        let deque: Deque<&str> = Deque::repeat("a", 2, 4);
+        assert_eq!(deque, VecDeque::from(["a", "a"]));
+        assert_eq!(deque.maxlen(), 4);
+    }
+
+    #[test]
+    fn test_initialization_filled_default() {
+        let deque: Deque<i32> = Deque::filled_default(3);
+        assert_eq!(deque, VecDeque::from([0, 0, 0]));
+        assert_eq!(deque.maxlen(), 3);
+
+        let deque: Deque<String> = Deque::filled_default(0);
+        assert!(deque.is_empty());
+    }
+
+    #[test]
+    fn test_initialization_concat() {
+        let first: Deque<i32> = (vec![1, 2, 3], 3).into();
+        let second: Deque<i32> = (vec![4, 5, 6], 5).into();
+
+        let deque = Deque::concat(&[first.clone(), second.clone()], 4);
+        assert_eq!(deque, VecDeque::from([3, 4, 5, 6]));
+        assert_eq!(deque.maxlen(), 4);
+        assert_eq!(deque.total_evicted(), 0);
+
+        let deque = Deque::concat(&[first, second], 10);
+        assert_eq!(deque, VecDeque::from([1, 2, 3, 4, 5, 6]));
+        assert_eq!(deque.maxlen(), 10);
+    }
+
+    #[test]
+    fn test_initialization_from_slice() {
+        let values = [1, 2, 3];
+        let deque: Deque<i32> = (&values[..], 2).into();
+        assert_eq!(deque, VecDeque::from([1, 2]));
+        assert_eq!(deque.maxlen(), 2);
+
+        let deque: Deque<i32> = (&values[..], 5).into();
+        assert_eq!(deque, VecDeque::from([1, 2, 3]));
+        assert_eq!(deque.maxlen(), 5);
+    }
+
+    #[test]
+    fn test_initialization_singleton_and_pair() {
+        let deque: Deque<i32> = Deque::singleton(1, 3);
+        assert_eq!(deque, VecDeque::from([1]));
+        assert_eq!(deque.maxlen(), 3);
+
+        let deque: Deque<i32> = Deque::pair(1, 2, 3);
+        assert_eq!(deque, VecDeque::from([1, 2]));
+        assert_eq!(deque.maxlen(), 3);
+
+        let deque: Deque<i32> = Deque::pair(1, 2, 1);
+        assert_eq!(deque, VecDeque::from([1]));
+        assert_eq!(deque.maxlen(), 1);
+
+        let deque: Deque<i32> = Deque::singleton(1, 0);
+        assert!(deque.is_empty());
+        assert_eq!(deque.maxlen(), 0);
+
+        let deque: Deque<i32> = Deque::pair(1, 2, 0);
+        assert!(deque.is_empty());
+    }
+
+    #[test]
+    fn test_chunk_into_deques() {
+        let deques = Deque::chunk_into_deques(vec![1, 2, 3, 4, 5, 6, 7], 3);
+        let lengths: Vec<usize> = deques.iter().map(Deque::len).collect();
+        assert_eq!(lengths, vec![3, 3, 1]);
+        assert!(deques.iter().all(|deque| deque.maxlen() == 3));
+        assert_eq!(deques[0], VecDeque::from([1, 2, 3]));
+        assert_eq!(deques[2], VecDeque::from([7]));
+
+        assert_eq!(Deque::chunk_into_deques(vec![1, 2], 2).len(), 1);
+        assert!(Deque::<i32>::chunk_into_deques(vec![], 2).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "maxlen must be non-zero")]
+    fn test_chunk_into_deques_zero_maxlen() {
+        let _ = Deque::chunk_into_deques(vec![1, 2], 0);
+    }
+
+    #[test]
+    fn test_initialization_capped_collect() {
+        let deque: Deque<i32> = Capped(3).collect_from(0..10);
+        assert_eq!(deque, VecDeque::from([7, 8, 9]));
+        assert_eq!(deque.maxlen(), 3);
+        assert_eq!(deque.total_evicted(), 0);
+
+        let deque: Deque<i32> = Capped(5).collect_from(vec![1, 2]);
+        assert_eq!(deque, VecDeque::from([1, 2]));
+        assert_eq!(deque.maxlen(), 5);
+    }
+
+    #[test]
+    fn test_initialization_capped_collect_zero() {
+        let deque: Deque<i32> = Capped(0).collect_from(0..10);
+        assert!(deque.is_empty());
+        assert_eq!(deque.maxlen(), 0);
+    }
+}
+
+#[cfg(test)]
+mod comparison_tests {
+    use super::Deque;
+    use std::collections::VecDeque;
+
+    #[test]
+    fn test_equal_deques() {
+        let mut deque1: Deque<i32> = Deque::new(3);
+        deque1.push_back(1);
+        deque1.push_back(2);
+        deque1.push_back(3);
+
+        let mut deque2: Deque<i32> = Deque::new(3);
+        deque2.push_back(1);
+        deque2.push_back(2);
+        deque2.push_back(3);
+
+        assert_eq!(
+            deque1, deque2,
+            "Deques with the same elements should be equal"
+        );
+    }
+
+    #[test]
+    fn test_unequal_deques_different_elements() {
+        let mut deque1: Deque<i32> = Deque::new(3);
+        deque1.push_back(1);
+        deque1.push_back(2);
+        deque1.push_back(3);
+
+        let mut deque2: Deque<i32> = Deque::new(3);
+        deque2.push_back(4);
+        deque2.push_back(5);
+        deque2.push_back(6);
+
+        assert_ne!(
+            deque1, deque2,
+            "Deques with different elements should not be equal"
+        );
+    }
+
+    #[test]
+    fn test_unequal_deques_different_lengths() {
+        let mut deque1: Deque<i32> = Deque::new(3);
+        deque1.push_back(1);
+        deque1.push_back(2);
+
+        let mut deque2: Deque<i32> = Deque::new(3);
+        deque2.push_back(1);
+        deque2.push_back(2);
+        deque2.push_back(3);
+
+        assert_ne!(
+            deque1, deque2,
+            "Deques with different lengths should not be equal"
+        );
+    }
+
+    #[test]
+    fn test_empty_deques_are_equal() {
+        let deque1: Deque<i32> = Deque::new(3);
+        let deque2: Deque<i32> = Deque::new(3);
+
+        assert_eq!(deque1, deque2, "Empty deques should be equal");
+    }
+
+    #[test]
+    fn test_partial_eq_with_subsequent_push_back() {
+        let mut deque1: Deque<i32> = Deque::new(3);
+        deque1.push_back(1);
+        deque1.push_back(2);
+        deque1.push_back(3);
+
+        let mut deque2: Deque<i32> = Deque::new(3);
+        deque2.push_back(1);
+        deque2.push_back(2);
+
+        assert_ne!(
+            deque1, deque2,
+            "Deque with missing elements should not be equal"
+        );
+
+        deque2.push_back(3);
+        assert_eq!(
+            deque1, deque2,
+            "After pushing the same element, deques should be equal"
+        );
+    }
+
+    #[test]
+    fn test_deque_eq_vecdeque() {
+        let deque: Deque<i32> = Deque::from_vec(vec![5, 6, 7], 3);
+        let vec_deque: VecDeque<i32> = vec![5, 6, 7].into_iter().collect();
+
+        assert_eq!(
+            deque, vec_deque,
+            "Deque should be equal to VecDeque with the same elements"
+        );
+    }
+
+    #[test]
+    fn test_vecdeque_eq_deque() {
+        let mut deque1: Deque<i32> = Deque::new(10);
+        deque1.push_back(1);
+        deque1.push_back(2);
+        deque1.push_back(3);
+
+        let mut vecdeque: VecDeque<i32> = VecDeque::new();
+        vecdeque.push_back(1);
+        vecdeque.push_back(2);
+        vecdeque.push_back(3);
+
+        assert_eq!(
+            vecdeque, deque1,
+            "VecDeque should be equal to Deque with the same elements"
+        );
+    }
+
+    #[test]
+    fn test_unequal_deque_and_vecdeque() {
+        let mut deque1: Deque<i32> = Deque::new(3);
+        deque1.push_back(1);
+        deque1.push_back(2);
+
+        let mut vecdeque: VecDeque<i32> = VecDeque::new();
+        vecdeque.push_back(3);
+        vecdeque.push_back(4);
+
+        assert_ne!(
+            deque1, vecdeque,
+            "Deque and VecDeque with different elements should not be equal"
+        );
+    }
+
+    #[test]
+    fn test_eq_ignore_order() {
+        let first: Deque<i32> = (vec![1, 2, 3], 3).into();
+        let second: Deque<i32> = (vec![3, 2, 1], 5).into();
+        assert!(first.eq_ignore_order(&second));
+        assert_ne!(first, second);
+
+        let first: Deque<i32> = (vec![1, 2, 2], 3).into();
+        let second: Deque<i32> = (vec![1, 2, 3], 3).into();
+        assert!(!first.eq_ignore_order(&second));
+
+        let first: Deque<i32> = (vec![1, 1, 2], 3).into();
+        let second: Deque<i32> = (vec![1, 2, 2], 3).into();
+        assert!(!first.eq_ignore_order(&second));
+    }
+
+    #[test]
+    fn test_common_prefix_len() {
+        let first: Deque<i32> = (vec![1, 2, 3], 3).into();
+        let second: Deque<i32> = (vec![1, 2, 9], 3).into();
+        assert_eq!(first.common_prefix_len(&second), 2);
+
+        let shorter: Deque<i32> = (vec![1, 2], 3).into();
+        assert_eq!(first.common_prefix_len(&shorter), 2);
+        assert_eq!(first.common_prefix_len(&first), 3);
+
+        let different: Deque<i32> = (vec![0, 2, 3], 3).into();
+        assert_eq!(first.common_prefix_len(&different), 0);
+    }
+
+    #[test]
+    fn test_eq_exact() {
+        let first: Deque<i32> = (vec![1, 2, 3], 3).into();
+        let second: Deque<i32> = (vec![1, 2, 3], 5).into();
+        assert_eq!(first, second);
+        assert!(!first.eq_exact(&second));
+
+        let third: Deque<i32> = (vec![1, 2, 3], 3).into();
+        assert!(first.eq_exact(&third));
+
+        let fourth: Deque<i32> = (vec![1, 2, 4], 3).into();
+        assert!(!first.eq_exact(&fourth));
+    }
+}
+
+#[cfg(test)]
+mod search_tests {
+    use super::Deque;
+
+    #[test]
+    fn test_contains_subsequence() {
+        let deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+        assert!(deque.contains_subsequence(&[1, 3]));
+        assert!(deque.contains_subsequence(&[1, 2, 3]));
+        assert!(!deque.contains_subsequence(&[3, 1]));
+        assert!(!deque.contains_subsequence(&[1, 2, 3, 4]));
+        assert!(deque.contains_subsequence(&[]));
+    }
+
+    #[test]
+    fn test_position_of_max_and_min() {
+        let deque: Deque<i32> = (vec![3, 1, 4, 1, 5], 5).into();
+        assert_eq!(deque.position_of_max(), Some(4));
+        assert_eq!(deque.position_of_min(), Some(1));
+
+        let deque: Deque<i32> = (vec![5, 2, 5, 2], 5).into();
+        assert_eq!(deque.position_of_max(), Some(0));
+        assert_eq!(deque.position_of_min(), Some(1));
+
+        let empty: Deque<i32> = Deque::new(5);
+        assert_eq!(empty.position_of_max(), None);
+        assert_eq!(empty.position_of_min(), None);
+    }
+
+    #[test]
+    fn test_positions() {
+        let deque: Deque<i32> = (vec![1, 2, 1, 3, 1], 5).into();
+        assert_eq!(deque.positions(|&x| x == 1), vec![0, 2, 4]);
+        assert_eq!(deque.positions(|&x| x > 2), vec![3]);
+        assert!(deque.positions(|&x| x > 3).is_empty());
+    }
+
+    #[test]
+    fn test_index_of() {
+        let deque: Deque<i32> = (vec![1, 2, 3, 4, 3], 5).into();
+        assert_eq!(deque.index_of(&3), Some(2));
+        assert_eq!(deque.index_of(&1), Some(0));
+        assert_eq!(deque.index_of(&5), None);
+    }
+
+    #[test]
+    fn test_count_leading_and_trailing() {
+        let deque: Deque<i32> = (vec![0, 0, 1, 2, 0], 5).into();
+        assert_eq!(deque.count_leading(|&x| x == 0), 2);
+        assert_eq!(deque.count_trailing(|&x| x == 0), 1);
+        assert_eq!(deque.count_leading(|&x| x > 0), 0);
+        assert_eq!(deque.count_trailing(|&x| x < 5), 5);
+    }
+
+    #[test]
+    fn test_unique_last() {
+        let deque: Deque<i32> = (vec![1, 2, 1, 3, 2], 5).into();
+        let unique = deque.unique_last();
+        assert_eq!(unique, Deque::from_vec(vec![1, 3, 2], 5));
+        assert_eq!(unique.maxlen(), 5);
+    }
+
+    #[test]
+    fn test_unique_last_empty() {
+        let deque: Deque<i32> = Deque::new(3);
+        assert!(deque.unique_last().is_empty());
+    }
+
+    #[test]
+    fn test_mask() {
+        let deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+        assert_eq!(deque.mask(|&x| x % 2 == 0), vec![false, true, false, true]);
+        assert!(Deque::<i32>::new(2).mask(|_| true).is_empty());
+    }
+}
+
+#[cfg(test)]
+mod iterator_tests {
+    use super::Deque;
+
+    #[test]
+    fn test_iter_indexed() {
+        let deque: Deque<i32> = (vec![10, 20, 30], 3).into();
+        let indexed: Vec<(usize, &i32)> = deque.iter_indexed().collect();
+        assert_eq!(indexed, vec![(0, &10), (1, &20), (2, &30)]);
+    }
+
+    #[test]
+    fn test_into_iter_rev() {
+        let deque: Deque<String> =
+            (vec!["1".to_string(), "2".to_string(), "3".to_string()], 3).into();
+        let reversed: Vec<String> = deque.into_iter_rev().collect();
+        assert_eq!(reversed, vec!["3", "2", "1"]);
+    }
+
+    #[test]
+    fn test_pairwise() {
+        let deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+        let pairs: Vec<(&i32, &i32)> = deque.pairwise().collect();
+        assert_eq!(pairs, vec![(&1, &2), (&2, &3)]);
+
+        let single: Deque<i32> = Deque::from(1, 3);
+        assert_eq!(single.pairwise().count(), 0);
+
+        let empty: Deque<i32> = Deque::new(3);
+        assert_eq!(empty.pairwise().count(), 0);
+    }
+
+    #[test]
+    fn test_with_index_from_back() {
+        let deque: Deque<i32> = (vec![10, 20, 30], 3).into();
+        let aged: Vec<(usize, &i32)> = deque.with_index_from_back().collect();
+        assert_eq!(aged, vec![(2, &10), (1, &20), (0, &30)]);
+
+        let empty: Deque<i32> = Deque::new(3);
+        assert_eq!(empty.with_index_from_back().count(), 0);
+    }
+
+    #[test]
+    fn test_pairwise_indexed() {
+        let deque: Deque<i32> = (vec![10, 20, 30], 3).into();
+        let pairs: Vec<_> = deque.pairwise_indexed().collect();
+        assert_eq!(pairs, vec![((0, &10), (1, &20)), ((1, &20), (2, &30))]);
+
+        let single: Deque<i32> = Deque::from(1, 3);
+        assert_eq!(single.pairwise_indexed().count(), 0);
+    }
+
+    #[test]
+    fn test_iter_counted() {
+        let deque: Deque<i32> = (vec![1, 2, 3], 5).into();
+        let mut iter = deque.iter_counted();
+        assert_eq!(iter.len(), deque.len());
+        iter.next();
+        assert_eq!(iter.len(), 2);
+
+        let empty: Deque<i32> = Deque::new(5);
+        assert_eq!(empty.iter_counted().len(), 0);
+    }
+
+    #[test]
+    fn test_cycle() {
+        let deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+        let cycled: Vec<i32> = deque.cycle().take(7).copied().collect();
+        assert_eq!(cycled, vec![1, 2, 3, 1, 2, 3, 1]);
+
+        let empty: Deque<i32> = Deque::new(3);
+        assert_eq!(empty.cycle().next(), None);
+    }
+
+    #[test]
+    fn test_iter_cloned() {
+        let deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+        let cloned: Vec<i32> = deque.iter_cloned().collect();
+        assert_eq!(cloned, vec![1, 2, 3]);
+        assert_eq!(deque, Deque::from_vec(vec![1, 2, 3], 3));
+    }
+}
+
+#[cfg(test)]
+mod access_tests {
+    use super::Deque;
+
+    #[test]
+    fn test_get_clamped() {
+        let deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+        assert_eq!(deque.get_clamped(0), Some(&1));
+        assert_eq!(deque.get_clamped(2), Some(&3));
+        assert_eq!(deque.get_clamped(9), Some(&3));
+
+        let empty: Deque<i32> = Deque::new(3);
+        assert_eq!(empty.get_clamped(0), None);
+        assert_eq!(empty.get_clamped(9), None);
+    }
+
+    #[test]
+    fn test_as_ptr_points_to_front() {
+        let mut deque: Deque<i32> = Deque::new(3);
+        deque.push_back(1);
+        deque.push_back(2);
+        deque.push_back(3);
+        // Wrap around the internal buffer
+        deque.push_back(4);
+        deque.push_front(0);
+
+        let ptr = deque.as_ptr();
+        assert!(deque.get(0).is_some_and(|front| std::ptr::eq(ptr, front)));
+        assert!(deque
+            .get(2)
+            .is_some_and(|last| std::ptr::eq(ptr.wrapping_add(2), last)));
+
+        let mut_ptr = deque.as_mut_ptr();
+        assert!(deque
+            .get(0)
+            .is_some_and(|front| std::ptr::eq(mut_ptr.cast_const(), front)));
+    }
+
+    #[test]
+    fn test_replace() {
+        let mut deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+        assert_eq!(deque.replace(0, 10), Ok(1));
+        assert_eq!(deque.front(), Some(&10));
+        assert_eq!(deque.len(), 3);
+    }
+
+    #[test]
+    fn test_replace_out_of_bounds() {
+        let mut deque: Deque<i32> = (vec![1, 2, 3], 5).into();
+        assert_eq!(deque.replace(3, 4), Err(4));
+        assert_eq!(deque.len(), 3);
+        assert_eq!(deque.back(), Some(&3));
+    }
+
+    #[test]
+    fn test_try_get() {
+        let deque: Deque<i32> = (vec![1, 2, 3], 5).into();
+        assert_eq!(deque.try_get(0), Ok(&1));
+        assert_eq!(deque.try_get(2), Ok(&3));
+    }
+
+    #[test]
+    fn test_try_get_out_of_bounds() {
+        let deque: Deque<i32> = (vec![1, 2, 3], 5).into();
+        let error = deque.try_get(4).expect_err("Index should be out of bounds");
+        assert_eq!(error.index, 4);
+        assert_eq!(error.len, 3);
+        assert_eq!(
+            error.to_string(),
+            "index out of bounds: the len is 3 but the index is 4"
+        );
+    }
+
+    #[test]
+    fn test_set() {
+        let mut deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+        assert_eq!(deque.set(2, 30), Ok(()));
+        assert_eq!(deque.back(), Some(&30));
+        assert_eq!(deque.len(), 3);
+    }
+
+    #[test]
+    fn test_set_out_of_bounds() {
+        let mut deque: Deque<String> = Deque::new(3);
+        assert_eq!(deque.set(0, "a".to_string()), Err("a".to_string()));
+        assert!(deque.is_empty());
+    }
+
+    #[test]
+    fn test_split_first_and_last() {
+        let deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+        assert_eq!(deque.split_first(), Some((&1, vec![&2, &3])));
+        assert_eq!(deque.split_last(), Some((&3, vec![&1, &2])));
+
+        let single: Deque<i32> = Deque::from(1, 3);
+        assert_eq!(single.split_first(), Some((&1, vec![])));
+        assert_eq!(single.split_last(), Some((&1, vec![])));
+
+        let empty: Deque<i32> = Deque::new(3);
+        assert_eq!(empty.split_first(), None);
+        assert_eq!(empty.split_last(), None);
+    }
+
+    #[test]
+    fn test_at_matches_get() {
+        let mut deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+        for index in 0..5 {
+            assert_eq!(deque.at(index), deque.get(index));
+        }
+
+        if let Some(value) = deque.at_mut(0) {
+            *value = 10;
+        }
+        assert_eq!(deque.get(0), Some(&10));
+        assert_eq!(deque.at_mut(3), None);
+    }
+
+    #[test]
+    fn test_from_back() {
+        let deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+        assert_eq!(deque.from_back(0), Some(&3));
+        assert_eq!(deque.from_back(1), Some(&2));
+        assert_eq!(deque.from_back(2), Some(&1));
+        assert_eq!(deque.from_back(3), None);
+        assert_eq!(deque.from_back(usize::MAX), None);
+    }
+
+    #[test]
+    fn test_peek_front_n_and_back_n() {
+        let deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+        assert_eq!(deque.peek_front_n(2), vec![&1, &2]);
+        assert_eq!(deque.peek_back_n(2), vec![&3, &4]);
+        assert_eq!(deque.peek_front_n(10), vec![&1, &2, &3, &4]);
+        assert_eq!(deque.peek_back_n(10), vec![&1, &2, &3, &4]);
+        assert!(deque.peek_front_n(0).is_empty());
+        assert_eq!(deque.len(), 4);
+    }
+
+    #[test]
+    fn test_front_chunk_and_back_chunk() {
+        let deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+
+        let front = deque.front_chunk(2);
+        assert_eq!(front, Deque::from_vec(vec![1, 2], 2));
+        assert_eq!(front.maxlen(), 2);
+
+        let back = deque.back_chunk(2);
+        assert_eq!(back, Deque::from_vec(vec![3, 4], 2));
+        assert_eq!(back.maxlen(), 2);
+
+        let all = deque.back_chunk(6);
+        assert_eq!(all.len(), 4);
+        assert_eq!(all.maxlen(), 6);
+        assert!(deque.front_chunk(0).is_empty());
+    }
+}
+
+#[cfg(test)]
+mod push_pop_tests {
+    use super::{Deque, OverflowPolicy};
+    use std::collections::VecDeque;
+
+    #[test]
+    fn test_pop_front_n() {
+        let mut deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+        assert_eq!(deque.pop_front_n(2), vec![1, 2]);
+        assert_eq!(deque.front(), Some(&3));
+        assert_eq!(deque.pop_front_n(10), vec![3, 4]);
+        assert!(deque.is_empty());
+        assert!(deque.pop_front_n(1).is_empty());
+    }
+
+    #[test]
+    fn test_pop_back_n() {
+        let mut deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+        assert_eq!(deque.pop_back_n(2), vec![4, 3]);
+        assert_eq!(deque.back(), Some(&2));
+        assert_eq!(deque.pop_back_n(10), vec![2, 1]);
+        assert!(deque.is_empty());
+    }
+
+    #[test]
+    fn test_push_frame() {
+        let mut deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+        let evicted = deque.push_frame(&[5, 6, 7]);
+        assert_eq!(evicted, vec![1, 2, 3]);
+        assert_eq!(deque, Deque::from_vec(vec![4, 5, 6, 7], 4));
+
+        let mut deque: Deque<i32> = Deque::new(4);
+        assert!(deque.push_frame(&[1, 2]).is_empty());
+        assert_eq!(deque.len(), 2);
+    }
+
+    #[test]
+    fn test_policy_evict_opposite() {
+        let mut deque: Deque<i32> = Deque::with_policy(3, OverflowPolicy::EvictOpposite);
+        deque.push_back(1);
+        deque.push_back(2);
+        deque.push_back(3);
+        assert_eq!(deque.push_back(4), Some(1));
+        assert_eq!(deque, VecDeque::from([2, 3, 4]));
+        assert_eq!(deque.push_front(0), Some(4));
+        assert_eq!(deque, VecDeque::from([0, 2, 3]));
+    }
+
+    #[test]
+    fn test_policy_reject_new() {
+        let mut deque: Deque<i32> = Deque::with_policy(3, OverflowPolicy::RejectNew);
+        deque.push_back(1);
+        deque.push_back(2);
+        deque.push_back(3);
+        assert_eq!(deque.push_back(4), Some(4));
+        assert_eq!(deque.push_front(0), Some(0));
+        assert_eq!(deque, VecDeque::from([1, 2, 3]));
+    }
+
+    #[test]
+    fn test_policy_evict_same_end() {
+        let mut deque: Deque<i32> = Deque::with_policy(3, OverflowPolicy::EvictSameEnd);
+        deque.push_back(1);
+        deque.push_back(2);
+        deque.push_back(3);
+        assert_eq!(deque.push_back(4), Some(3));
+        assert_eq!(deque, VecDeque::from([1, 2, 4]));
+        assert_eq!(deque.push_front(0), Some(1));
+        assert_eq!(deque, VecDeque::from([0, 2, 4]));
+    }
+
+    #[test]
+    fn test_push_back_draining() {
+        let mut deque: Deque<i32> = Deque::new(3);
+        let mut overflow = Vec::new();
+        for value in 1..=3 {
+            deque.push_back_draining(value, &mut overflow);
+        }
+        assert!(overflow.is_empty());
+
+        for value in 4..=6 {
+            deque.push_back_draining(value, &mut overflow);
+        }
+        assert_eq!(overflow, vec![1, 2, 3]);
+        assert_eq!(deque, VecDeque::from([4, 5, 6]));
+    }
+
+    #[test]
+    fn test_refill_from_slice() {
+        let mut deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+        assert_eq!(deque.refill_from_slice(&[4, 5]), vec![1, 2]);
+        assert_eq!(deque, VecDeque::from([3, 4, 5]));
+    }
+
+    #[test]
+    fn test_push_zero_maxlen() {
+        let mut deque: Deque<i32> = Deque::new(0);
+        assert_eq!(deque.push_back(1), Some(1));
+        assert_eq!(deque.push_front(2), Some(2));
+        assert!(deque.is_empty());
+        assert_eq!(deque.total_evicted(), 0);
+
+        let mut deque: Deque<i32> = Deque::with_policy(0, OverflowPolicy::EvictSameEnd);
+        assert_eq!(deque.push_back(1), Some(1));
+        assert!(deque.is_empty());
+    }
+
+    #[test]
+    fn test_push_frame_with_policy() {
+        let mut deque: Deque<i32> = Deque::with_policy(2, OverflowPolicy::RejectNew);
+        assert_eq!(deque.push_frame(&[1, 2, 3, 4]), vec![3, 4]);
+        assert_eq!(deque, VecDeque::from([1, 2]));
+        assert_eq!(deque.refill_from_slice(&[5]), vec![5]);
+        let mut overflow = Vec::new();
+        deque.push_back_draining(6, &mut overflow);
+        assert_eq!(overflow, vec![6]);
+        assert_eq!(deque, VecDeque::from([1, 2]));
+
+        let mut deque: Deque<i32> = Deque::with_policy(2, OverflowPolicy::EvictSameEnd);
+        deque.push_frame(&[1, 2]);
+        assert_eq!(deque.push_frame(&[3, 4]), vec![2, 3]);
+        assert_eq!(deque, VecDeque::from([1, 4]));
+        assert_eq!(deque.refill_from_slice(&[5]), vec![4]);
+        let mut overflow = Vec::new();
+        deque.push_back_draining(6, &mut overflow);
+        assert_eq!(overflow, vec![5]);
+        assert_eq!(deque, VecDeque::from([1, 6]));
+    }
+
+    #[test]
+    fn test_try_push_back_all() {
+        let mut deque: Deque<i32> = Deque::new(3);
+        assert_eq!(deque.try_push_back_all(vec![1, 2, 3, 4, 5]), vec![4, 5]);
+        assert_eq!(deque, VecDeque::from([1, 2, 3]));
+
+        deque.pop_front();
+        assert_eq!(deque.try_push_back_all([6, 7]), vec![7]);
+        assert_eq!(deque, VecDeque::from([2, 3, 6]));
+
+        assert!(deque.try_push_back_all([]).is_empty());
+    }
+
+    #[test]
+    fn test_checked_push_back_zero_maxlen() {
+        let mut deque: Deque<i32> = Deque::new(0);
+        assert_eq!(deque.checked_push_back(1), Err(1));
+        assert!(deque.is_empty());
+    }
+
+    #[test]
+    fn test_checked_push_back() {
+        let mut deque: Deque<i32> = Deque::new(2);
+        assert_eq!(deque.checked_push_back(1), Ok(None));
+        assert_eq!(deque.checked_push_back(2), Ok(None));
+        assert_eq!(deque.checked_push_back(3), Ok(Some(1)));
+        assert_eq!(deque, VecDeque::from([2, 3]));
+    }
+
+    #[test]
+    fn test_cycle_push() {
+        let mut deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+        assert_eq!(deque.cycle_push(4), 1);
+        assert_eq!(deque.cycle_push(5), 2);
+        assert_eq!(deque, VecDeque::from([3, 4, 5]));
+
+        deque.set_maxlen_lazy(2);
+        assert_eq!(deque.cycle_push(6), 3);
+        assert_eq!(deque, VecDeque::from([5, 6]));
+    }
+
+    #[test]
+    #[should_panic(expected = "cycle_push requires a full deque")]
+    fn test_cycle_push_not_full() {
+        let mut deque: Deque<i32> = (vec![1, 2], 3).into();
+        deque.cycle_push(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "cycle_push requires a full deque")]
+    fn test_cycle_push_zero_maxlen() {
+        let mut deque: Deque<i32> = Deque::new(0);
+        deque.cycle_push(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "cycle_push requires a full deque")]
+    fn test_cycle_push_zero_maxlen_with_elements() {
+        let mut deque: Deque<i32> = (vec![1], 1).into();
+        deque.set_maxlen_lazy(0);
+        deque.cycle_push(2);
+    }
+
+    #[test]
+    fn test_expire() {
+        let mut deque: Deque<(u32, char)> =
+            (vec![(1, 'a'), (2, 'b'), (4, 'c'), (4, 'd'), (7, 'e')], 5).into();
+
+        let expired = deque.expire(|&(key, _)| key, 4);
+        assert_eq!(expired, vec![(1, 'a'), (2, 'b')]);
+        assert_eq!(deque.front(), Some(&(4, 'c')));
+
+        assert!(deque.expire(|&(key, _)| key, 0).is_empty());
+
+        let expired = deque.expire(|&(key, _)| key, 10);
+        assert_eq!(expired.len(), 3);
+        assert!(deque.is_empty());
+    }
+
+    #[test]
+    fn test_extend() {
+        let mut deque: Deque<i32> = Deque::new(2);
+        deque.extend(vec![1, 2, 3]);
+        assert_eq!(deque, VecDeque::from([2, 3]));
+    }
+
+    #[test]
+    fn test_extend_from_references() {
+        let mut deque: Deque<i32> = Deque::new(2);
+        deque.extend([1, 2, 3].iter());
+        assert_eq!(deque, VecDeque::from([2, 3]));
+
+        let other: Deque<i32> = (vec![4, 5], 2).into();
+        deque.extend(&other);
+        assert_eq!(deque, VecDeque::from([4, 5]));
+    }
+
+    #[test]
+    fn test_prepend_from_slice() {
+        let mut deque: Deque<i32> = (vec![2, 3], 3).into();
+        assert_eq!(deque.prepend_from_slice(&[0, 1]), vec![3]);
+        assert_eq!(deque, VecDeque::from([0, 1, 2]));
+    }
+
+    #[test]
+    fn test_prepend_from_slice_longer_than_maxlen() {
+        let mut deque: Deque<i32> = (vec![5], 2).into();
+        assert_eq!(deque.prepend_from_slice(&[0, 1, 2]), vec![5, 2]);
+        assert_eq!(deque, VecDeque::from([0, 1]));
+    }
+
+    #[test]
+    fn test_drain_back_while() {
+        let mut deque: Deque<i32> = (vec![1, 2, 5, 6], 4).into();
+        assert_eq!(deque.drain_back_while(|&x| x > 4), vec![6, 5]);
+        assert_eq!(deque, VecDeque::from([1, 2]));
+        assert!(deque.drain_back_while(|&x| x > 4).is_empty());
+        assert_eq!(deque.drain_back_while(|_| true), vec![2, 1]);
+        assert!(deque.is_empty());
+    }
+
+    #[test]
+    fn test_drain_front_while() {
+        let mut deque: Deque<i32> = (vec![1, 2, 5, 6], 4).into();
+        assert_eq!(deque.drain_front_while(|&x| x < 4), vec![1, 2]);
+        assert_eq!(deque, VecDeque::from([5, 6]));
+    }
+
+    #[test]
+    fn test_total_evicted() {
+        let mut deque: Deque<i32> = Deque::new(3);
+        for value in 0..5 {
+            deque.push_back(value);
+        }
+        assert_eq!(deque.total_evicted(), 2);
+
+        deque.push_front(9);
+        assert_eq!(deque.total_evicted(), 3);
+
+        deque.reset_eviction_count();
+        assert_eq!(deque.total_evicted(), 0);
+        deque.pop_back();
+        deque.push_back(7);
+        assert_eq!(deque.total_evicted(), 0);
+    }
+
+    #[test]
+    fn test_total_evicted_cycle_push() {
+        let mut deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+        deque.cycle_push(4);
+        deque.cycle_push(5);
+        assert_eq!(deque.total_evicted(), 2);
+    }
+
+    #[test]
+    fn test_total_evicted_insert_sorted() {
+        let mut deque: Deque<i32> = (vec![1, 3, 5], 3).into();
+        assert_eq!(deque.insert_sorted(4), Some(1));
+        assert_eq!(deque.total_evicted(), 1);
+
+        // A rejected element that would be the smallest is not an eviction
+        assert_eq!(deque.insert_sorted(0), Some(0));
+        assert_eq!(deque.total_evicted(), 1);
+
+        let mut deque: Deque<i32> = Deque::new(3);
+        deque.insert_sorted(2);
+        assert_eq!(deque.total_evicted(), 0);
+    }
+
+    #[test]
+    fn test_total_evicted_ignores_rejected() {
+        let mut deque: Deque<i32> = Deque::with_policy(1, OverflowPolicy::RejectNew);
+        deque.push_back(1);
+        deque.push_back(2);
+        deque.push_front(3);
+        assert_eq!(deque.total_evicted(), 0);
+
+        let mut deque: Deque<i32> = Deque::with_policy(1, OverflowPolicy::EvictSameEnd);
+        deque.push_back(1);
+        deque.push_back(2);
+        assert_eq!(deque.total_evicted(), 1);
+    }
+
+    #[test]
+    fn test_total_evicted_ignored_by_equality() {
+        let mut evicting: Deque<i32> = Deque::new(2);
+        evicting.extend([0, 1, 2]);
+        let fresh: Deque<i32> = (vec![1, 2], 2).into();
+        assert_eq!(evicting, fresh);
+        assert!(evicting.eq_exact(&fresh));
+    }
+
+    #[test]
+    fn test_shift_fill() {
+        let mut deque: Deque<i32> = Deque::new(3);
+        for value in 0..3 {
+            deque.shift_fill(value);
+        }
+        assert_eq!(deque.len(), 3);
+
+        for value in 3..10 {
+            deque.shift_fill(value);
+            assert_eq!(deque.len(), 3);
+            assert_eq!(deque.back(), Some(&value));
+        }
+        assert_eq!(deque, VecDeque::from([7, 8, 9]));
+        assert_eq!(deque.total_evicted(), 7);
+    }
+
+    #[test]
+    fn test_shift_fill_ignores_policy() {
+        let mut deque: Deque<i32> = Deque::with_policy(3, OverflowPolicy::RejectNew);
+        deque.extend([1, 2, 3]);
+        deque.shift_fill(4);
+        assert_eq!(deque, VecDeque::from([2, 3, 4]));
+        assert_eq!(deque.total_evicted(), 1);
+
+        let mut deque: Deque<i32> = Deque::with_policy(3, OverflowPolicy::EvictSameEnd);
+        deque.extend([1, 2, 3]);
+        deque.shift_fill(4);
+        assert_eq!(deque, VecDeque::from([2, 3, 4]));
+
+        let mut deque: Deque<i32> = Deque::new(0);
+        deque.shift_fill(1);
+        assert!(deque.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod conversion_tests {
+    use super::Deque;
+    use std::collections::VecDeque;
+
+    #[test]
+    fn test_clone_into_vec_reuses_destination() {
+        let mut dst = Vec::new();
+
+        let deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+        deque.clone_into_vec(&mut dst);
+        assert_eq!(dst, vec![1, 2, 3, 4]);
+
+        let deque: Deque<i32> = (vec![5, 6], 4).into();
+        deque.clone_into_vec(&mut dst);
+        assert_eq!(dst, vec![5, 6]);
+        assert_eq!(deque.len(), 2);
+    }
+
+    #[test]
+    fn test_split_when() {
+        let deque: Deque<i32> = (vec![1, 0, 2, 3, 0, 4], 6).into();
+        assert_eq!(
+            deque.split_when(|&x| x == 0),
+            vec![vec![1], vec![2, 3], vec![4]]
+        );
+
+        let deque: Deque<i32> = (vec![0, 1, 0, 0], 6).into();
+        assert_eq!(
+            deque.split_when(|&x| x == 0),
+            vec![vec![], vec![1], vec![], vec![]]
+        );
+
+        let deque: Deque<i32> = (vec![1, 2], 6).into();
+        assert_eq!(deque.split_when(|&x| x == 0), vec![vec![1, 2]]);
+    }
+
+    #[test]
+    fn test_flatten() {
+        let deque: Deque<Vec<i32>> = (vec![vec![1, 2], vec![3], vec![], vec![4, 5]], 4).into();
+        assert_eq!(deque.flatten(), vec![1, 2, 3, 4, 5]);
+
+        let empty: Deque<Vec<i32>> = Deque::new(4);
+        assert!(empty.flatten().is_empty());
+    }
+
+    #[test]
+    fn test_into_parts_and_from_parts_round_trip() {
+        let deque: Deque<i32> = (vec![1, 2, 3], 5).into();
+        let (inner, maxlen) = deque.clone().into_parts();
+        assert_eq!(inner, VecDeque::from([1, 2, 3]));
+        assert_eq!(maxlen, 5);
+
+        let rebuilt = Deque::from_parts(inner, maxlen);
+        assert_eq!(rebuilt, deque);
+        assert_eq!(rebuilt.maxlen(), deque.maxlen());
+    }
+
+    #[test]
+    fn test_from_parts_truncates() {
+        let deque = Deque::from_parts(VecDeque::from([1, 2, 3, 4]), 2);
+        assert_eq!(deque, VecDeque::from([1, 2]));
+        assert_eq!(deque.maxlen(), 2);
+    }
+
+    #[test]
+    fn test_into_array() {
+        let deque: Deque<i32> = Deque::from_vec(vec![1, 2, 3], 3);
+        assert_eq!(deque.into_array::<3>(), Ok([1, 2, 3]));
+
+        let mut deque: Deque<i32> = Deque::new(2);
+        deque.push_back(1);
+        deque.push_back(2);
+        deque.push_back(3);
+        let array: [i32; 2] = deque.into_array().expect("Deque should be full");
+        assert_eq!(array, [2, 3]);
+    }
+
+    #[test]
+    fn test_into_array_wrong_length() {
+        let deque: Deque<i32> = Deque::from_vec(vec![1, 2], 3);
+        let deque = deque
+            .into_array::<3>()
+            .expect_err("Partially filled deque should not convert");
+        assert_eq!(deque, VecDeque::from([1, 2]));
+        assert_eq!(deque.maxlen(), 3);
+    }
+
+    #[test]
+    fn test_python_repr() {
+        let deque: Deque<i32> = (vec![1, 2, 3], 5).into();
+        assert_eq!(deque.python_repr(), "deque([1, 2, 3], maxlen=5)");
+
+        let empty: Deque<i32> = Deque::new(2);
+        assert_eq!(empty.python_repr(), "deque([], maxlen=2)");
+    }
+
+    #[test]
+    fn test_take_while_and_skip_while() {
+        let deque: Deque<i32> = (vec![1, 2, 3, 1], 4).into();
+
+        let taken = deque.take_while(|&x| x < 3);
+        assert_eq!(taken, VecDeque::from([1, 2]));
+        assert_eq!(taken.maxlen(), 4);
+
+        let skipped = deque.skip_while(|&x| x < 3);
+        assert_eq!(skipped, VecDeque::from([3, 1]));
+        assert_eq!(skipped.maxlen(), 4);
+
+        assert!(deque.take_while(|&x| x > 5).is_empty());
+        assert_eq!(deque.skip_while(|&x| x > 5), deque);
+    }
+
+    #[test]
+    fn test_into_full_vec() {
+        let deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+        assert_eq!(deque.into_full_vec(), Ok(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn test_into_full_vec_partial() {
+        let deque: Deque<i32> = (vec![1, 2], 3).into();
+        let deque = deque
+            .into_full_vec()
+            .expect_err("Partially filled deque should not convert");
+        assert_eq!(deque, VecDeque::from([1, 2]));
+        assert_eq!(deque.maxlen(), 3);
+    }
+
+    #[test]
+    fn test_to_bytes() {
+        let mut deque: Deque<u8> = Deque::new(4);
+        deque.push_back(b'b');
+        deque.push_back(b'c');
+        deque.push_front(b'a');
+        let (front, back) = deque.as_byte_slices();
+        assert_eq!(front.len() + back.len(), 3);
+        assert_eq!(deque.to_bytes(), b"abc".to_vec());
+        assert!(Deque::<u8>::new(2).to_bytes().is_empty());
+    }
+}
+
+#[cfg(test)]
+mod ordering_tests {
+    use super::Deque;
+    use std::collections::VecDeque;
+
+    #[test]
+    fn test_monotonic_increasing() {
+        let deque: Deque<i32> = (vec![1, 2, 2, 3], 4).into();
+        assert!(deque.is_monotonic_increasing());
+        assert!(!deque.is_strictly_increasing());
+        assert!(!deque.is_monotonic_decreasing());
+
+        let deque: Deque<i32> = (vec![1, 2, 3], 4).into();
+        assert!(deque.is_strictly_increasing());
+    }
+
+    #[test]
+    fn test_monotonic_decreasing() {
+        let deque: Deque<i32> = (vec![3, 2, 1], 3).into();
+        assert!(deque.is_monotonic_decreasing());
+        assert!(deque.is_strictly_decreasing());
+        assert!(!deque.is_monotonic_increasing());
+
+        let deque: Deque<i32> = (vec![3, 3, 1], 3).into();
+        assert!(deque.is_monotonic_decreasing());
+        assert!(!deque.is_strictly_decreasing());
+    }
+
+    #[test]
+    fn test_monotonic_trivial() {
+        let empty: Deque<i32> = Deque::new(3);
+        assert!(empty.is_monotonic_increasing());
+        assert!(empty.is_strictly_decreasing());
+
+        let single: Deque<i32> = Deque::from(1, 3);
+        assert!(single.is_strictly_increasing());
+        assert!(single.is_strictly_decreasing());
+    }
+
+    #[test]
+    fn test_is_palindrome() {
+        let deque: Deque<i32> = (vec![1, 2, 1], 3).into();
+        assert!(deque.is_palindrome());
+
+        let deque: Deque<i32> = (vec![1, 2, 2, 1], 4).into();
+        assert!(deque.is_palindrome());
+
+        let deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+        assert!(!deque.is_palindrome());
+
+        let empty: Deque<i32> = Deque::new(3);
+        assert!(empty.is_palindrome());
+        assert!(Deque::from(1, 3).is_palindrome());
+    }
+
+    #[test]
+    fn test_merge_sorted() {
+        let first: Deque<i32> = (vec![1, 3, 5], 3).into();
+        let second: Deque<i32> = (vec![2, 4, 6], 3).into();
+
+        let merged = first.merge_sorted(&second, 6);
+        assert_eq!(merged, VecDeque::from([1, 2, 3, 4, 5, 6]));
+        assert_eq!(merged.maxlen(), 6);
+
+        let merged = first.merge_sorted(&second, 4);
+        assert_eq!(merged, VecDeque::from([1, 2, 3, 4]));
+        assert_eq!(merged.maxlen(), 4);
+
+        let merged = first.merge_sorted(&Deque::new(3), 10);
+        assert_eq!(merged, VecDeque::from([1, 3, 5]));
+        assert_eq!(merged.maxlen(), 10);
+    }
+
+    #[test]
+    fn test_insert_sorted() {
+        let mut deque: Deque<i32> = Deque::new(4);
+        for value in [5, 1, 4, 2] {
+            assert_eq!(deque.insert_sorted(value), None);
+        }
+        assert_eq!(deque, VecDeque::from([1, 2, 4, 5]));
+    }
+
+    #[test]
+    fn test_insert_sorted_full_evicts_minimum() {
+        let mut deque: Deque<i32> = (vec![1, 3, 5, 7], 4).into();
+        assert_eq!(deque.insert_sorted(4), Some(1));
+        assert_eq!(deque, VecDeque::from([3, 4, 5, 7]));
+
+        assert_eq!(deque.insert_sorted(9), Some(3));
+        assert_eq!(deque, VecDeque::from([4, 5, 7, 9]));
+
+        assert_eq!(deque.insert_sorted(2), Some(2));
+        assert_eq!(deque, VecDeque::from([4, 5, 7, 9]));
+    }
+}
+
+#[cfg(test)]
+mod statistics_tests {
+    use super::Deque;
+
+    #[test]
+    fn test_prefix_scan() {
+        let deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+        assert_eq!(deque.prefix_scan(0, |acc, x| acc + x), vec![1, 3, 6]);
+
+        let empty: Deque<i32> = Deque::new(3);
+        assert!(empty.prefix_scan(0, |acc, x| acc + x).is_empty());
+    }
+
+    #[test]
+    fn test_count_distinct() {
+        let deque: Deque<i32> = (vec![1, 2, 2, 3, 3, 3], 6).into();
+        assert_eq!(deque.count_distinct(), 3);
+
+        let empty: Deque<i32> = Deque::new(6);
+        assert_eq!(empty.count_distinct(), 0);
+    }
+
+    #[test]
+    fn test_mode() {
+        let deque: Deque<i32> = (vec![1, 2, 2, 3, 3, 3], 6).into();
+        assert_eq!(deque.mode(), Some(&3));
+
+        let deque: Deque<i32> = (vec![4, 1, 1, 4], 6).into();
+        assert_eq!(deque.mode(), Some(&4));
+
+        let empty: Deque<i32> = Deque::new(6);
+        assert_eq!(empty.mode(), None);
+    }
+
+    #[test]
+    fn test_histogram() {
+        let deque: Deque<i32> = (vec![1, 1, 2, 3, 3, 3], 6).into();
+        let histogram = deque.histogram();
+        assert_eq!(histogram.len(), 3);
+        assert_eq!(histogram.get(&1), Some(&2));
+        assert_eq!(histogram.get(&2), Some(&1));
+        assert_eq!(histogram.get(&3), Some(&3));
+        assert_eq!(histogram.get(&4), None);
+    }
+
+    #[test]
+    fn test_prefix_sum_threshold() {
+        let deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+        assert_eq!(deque.prefix_sum_threshold(4), Some(2));
+        assert_eq!(deque.prefix_sum_threshold(0), Some(0));
+        assert_eq!(deque.prefix_sum_threshold(9), Some(3));
+        assert_eq!(deque.prefix_sum_threshold(10), None);
+
+        let deque: Deque<f64> = (vec![0.5, 0.5, 0.5], 4).into();
+        assert_eq!(deque.prefix_sum_threshold(1.0), Some(2));
+    }
+
+    #[test]
+    fn test_diffs() {
+        let deque: Deque<i32> = (vec![1, 3, 6, 10], 4).into();
+        assert_eq!(deque.diffs(), vec![2, 3, 4]);
+
+        let deque: Deque<i32> = (vec![5, 2], 4).into();
+        assert_eq!(deque.diffs(), vec![-3]);
+
+        assert!(Deque::from(1, 4).diffs().is_empty());
+        assert!(Deque::<i32>::new(4).diffs().is_empty());
+    }
+
+    #[test]
+    fn test_try_fold_short_circuits() {
+        let deque: Deque<i32> = (vec![1, 2, 3, 4, 5], 5).into();
+        let mut visited = 0;
+        let result = deque.try_fold(0, |acc, &x| {
+            visited += 1;
+            let sum = acc + x;
+            if sum > 5 {
+                Err(sum)
+            } else {
+                Ok(sum)
+            }
+        });
+        assert_eq!(result, Err(6));
+        assert_eq!(visited, 3);
+
+        let total: Result<i32, i32> = deque.try_fold(0, |acc, &x| Ok(acc + x));
+        assert_eq!(total, Ok(15));
+    }
+}
+
+#[cfg(test)]
+mod mutation_tests {
+    use super::{Deque, OverflowPolicy};
+
+    #[test]
+    fn test_swap_with() {
+        let mut first: Deque<i32> = (vec![1, 2], 2).into();
+        let mut second: Deque<i32> = (vec![3, 4, 5], 5).into();
+        first.swap_with(&mut second);
+
+        assert_eq!(first, Deque::from_vec(vec![3, 4, 5], 5));
+        assert_eq!(first.maxlen(), 5);
+        assert_eq!(second, Deque::from_vec(vec![1, 2], 2));
+        assert_eq!(second.maxlen(), 2);
+
+        let mut rejecting: Deque<i32> = Deque::with_policy(1, OverflowPolicy::RejectNew);
+        rejecting.push_back(1);
+        let mut evicting: Deque<i32> = Deque::new(2);
+        evicting.extend([1, 2, 3, 4]);
+        rejecting.swap_with(&mut evicting);
+
+        assert_eq!(rejecting.overflow_policy(), OverflowPolicy::EvictOpposite);
+        assert_eq!(rejecting.total_evicted(), 2);
+        assert_eq!(rejecting.maxlen(), 2);
+        assert_eq!(evicting.overflow_policy(), OverflowPolicy::RejectNew);
+        assert_eq!(evicting.total_evicted(), 0);
+        assert_eq!(evicting.maxlen(), 1);
+    }
+
+    #[test]
+    fn test_shrink_to() {
+        let mut deque: Deque<i32> = Deque::new(1024);
+        assert!(deque.capacity() >= 1024);
+        deque.push_back(1);
+        deque.push_back(2);
+        deque.push_back(3);
+
+        deque.shrink_to(16);
+        assert!(deque.capacity() >= 16);
+        assert!(deque.capacity() < 1024);
+
+        deque.shrink_to(0);
+        assert!(deque.capacity() >= 3);
+        assert_eq!(deque.len(), 3);
+    }
+
+    #[test]
+    fn test_align_to_front() {
+        let mut deque: Deque<i32> = Deque::new(4);
+        for value in 0..6 {
+            deque.push_back(value);
+        }
+        assert!(!deque.as_slices().1.is_empty());
+
+        deque.align_to_front();
+        assert!(deque.as_slices().1.is_empty());
+        assert_eq!(deque.as_slices().0, &[2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_try_with_inner_mut() {
+        let mut deque: Deque<i32> = (vec![1, 2, 3], 4).into();
+        let result = deque.try_with_inner_mut(|inner| {
+            inner.retain(|&x| x != 2);
+            inner.push_front(0);
+            inner.len()
+        });
+        assert_eq!(result, Ok(3));
+        assert_eq!(deque, Deque::from_vec(vec![0, 1, 3], 4));
+    }
+
+    #[test]
+    fn test_try_with_inner_mut_over_capacity() {
+        let mut deque: Deque<i32> = (vec![1, 2, 3], 4).into();
+        let error = deque
+            .try_with_inner_mut(|inner| inner.extend([4, 5, 6]))
+            .expect_err("Closure should exceed capacity");
+        assert_eq!(error.len, 6);
+        assert_eq!(error.maxlen, 4);
+        assert_eq!(deque, Deque::from_vec(vec![1, 2, 3, 4], 4));
+    }
+
+    #[test]
+    fn test_try_reserve() {
+        let mut deque: Deque<i32> = Deque::from_vec(vec![1, 2], 1000);
+        assert!(deque.try_reserve(100).is_ok());
+        assert!(deque.capacity() >= 102);
+    }
+
+    #[test]
+    fn test_try_reserve_capped_at_maxlen() {
+        let mut deque: Deque<i32> = Deque::from_vec(vec![1, 2], 4);
+        // Would overflow without capping to the remaining space
+        assert!(deque.try_reserve(usize::MAX).is_ok());
+        assert_eq!(deque.capacity(), 4);
+    }
+
+    #[test]
+    fn test_truncate_front() {
+        let mut deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+        deque.truncate_front(2);
+        assert_eq!(deque, Deque::from_vec(vec![3, 4], 4));
+
+        deque.truncate_front(5);
+        assert_eq!(deque.len(), 2);
+
+        deque.truncate_front(0);
+        assert!(deque.is_empty());
+    }
+
+    #[test]
+    fn test_rotate_to_value_front() {
+        let mut deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+        assert!(deque.rotate_to_value_front(&3));
+        assert_eq!(deque, Deque::from_vec(vec![3, 4, 1, 2], 4));
+
+        assert!(deque.rotate_to_value_front(&3));
+        assert_eq!(deque, Deque::from_vec(vec![3, 4, 1, 2], 4));
+    }
+
+    #[test]
+    fn test_rotate_to_value_front_not_found() {
+        let mut deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+        assert!(!deque.rotate_to_value_front(&5));
+        assert_eq!(deque, Deque::from_vec(vec![1, 2, 3, 4], 4));
+    }
+
+    #[test]
+    fn test_set_maxlen_lazy() {
+        let mut deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+        deque.set_maxlen_lazy(2);
+        assert_eq!(deque.maxlen(), 2);
+        assert_eq!(deque, Deque::from_vec(vec![1, 2, 3, 4], 4));
+        assert_eq!(deque.capacity(), 4);
+
+        assert_eq!(deque.push_back(5), Some(1));
+        assert_eq!(deque, Deque::from_vec(vec![4, 5], 2));
+        assert_eq!(deque.total_evicted(), 3);
+        assert_eq!(deque.capacity(), 2);
+
+        assert_eq!(deque.push_front(0), Some(5));
+        assert_eq!(deque, Deque::from_vec(vec![0, 4], 2));
+    }
+
+    #[test]
+    fn test_set_maxlen_lazy_push_converges() {
+        let mut deque: Deque<i32> = (vec![1, 2, 3, 4, 5], 5).into();
+        deque.set_maxlen_lazy(3);
+        deque.push_back(6);
+        assert_eq!(deque.len(), deque.maxlen());
+        assert_eq!(deque, Deque::from_vec(vec![4, 5, 6], 3));
+
+        for value in 7..10 {
+            deque.push_back(value);
+            assert_eq!(deque.len(), deque.maxlen());
+        }
+
+        let mut deque: Deque<i32> = Deque::with_policy(4, OverflowPolicy::EvictSameEnd);
+        deque.extend([1, 2, 3, 4]);
+        deque.set_maxlen_lazy(2);
+        assert_eq!(deque.push_front(0), Some(1));
+        assert_eq!(deque.len(), deque.maxlen());
+        assert_eq!(deque, Deque::from_vec(vec![0, 4], 2));
+
+        let mut deque: Deque<i32> = Deque::with_policy(4, OverflowPolicy::RejectNew);
+        deque.extend([1, 2, 3, 4]);
+        deque.set_maxlen_lazy(2);
+        assert_eq!(deque.push_back(5), Some(5));
+        assert_eq!(deque.len(), 4);
+    }
+
+    #[test]
+    fn test_set_maxlen_lazy_grow() {
+        let mut deque: Deque<i32> = (vec![1, 2], 2).into();
+        deque.set_maxlen_lazy(3);
+        assert_eq!(deque.push_back(3), None);
+        assert_eq!(deque.len(), 3);
+    }
+
+    #[test]
+    fn test_retain_last_n() {
+        let mut deque: Deque<i32> = (vec![1, 2, 3, 4, 5], 5).into();
+        deque.retain_last_n(2);
+        assert_eq!(deque, Deque::from_vec(vec![4, 5], 5));
+        assert_eq!(deque.maxlen(), 5);
+    }
+
+    #[test]
+    fn test_heap_size() {
+        let mut deque: Deque<i32> = Deque::new(8);
+        for value in 0..10 {
+            deque.push_back(value);
+        }
+        let size = deque.heap_size();
+        assert!(size >= 8 * std::mem::size_of::<i32>());
+        assert_eq!(size % std::mem::size_of::<i32>(), 0);
+
+        let deque: Deque<()> = Deque::new(8);
+        assert_eq!(deque.heap_size(), 0);
+    }
+
+    #[test]
+    fn test_lock_maxlen() {
+        let mut deque: Deque<i32> = (vec![1, 2, 3], 10).into();
+        deque.lock_maxlen();
+        assert_eq!(deque.maxlen(), 3);
+        assert_eq!(deque.push_back(4), Some(1));
+        assert_eq!(deque.push_front(0), Some(4));
+        assert_eq!(deque, Deque::from_vec(vec![0, 2, 3], 3));
+    }
+
+    #[test]
+    fn test_normalize() {
+        let mut deque: Deque<i32> = (vec![1, 2, 3, 4, 5], 5).into();
+        deque.set_maxlen_lazy(3);
+        assert_eq!(deque.normalize(), vec![1, 2]);
+        assert_eq!(deque, Deque::from_vec(vec![3, 4, 5], 3));
+
+        assert!(deque.normalize().is_empty());
+        assert_eq!(deque.len(), 3);
+        assert_eq!(deque.total_evicted(), 2);
+    }
+
+    #[test]
+    fn test_retain_counting() {
+        let mut deque: Deque<i32> = (vec![1, 2, 3, 4, 5], 5).into();
+        assert_eq!(deque.retain_counting(|&x| x % 2 == 0), 3);
+        assert_eq!(deque, Deque::from_vec(vec![2, 4], 5));
+        assert_eq!(deque.retain_counting(|_| true), 0);
+    }
+
+    #[test]
+    fn test_slice_lengths() {
+        let mut deque: Deque<i32> = Deque::new(4);
+        for value in 0..6 {
+            deque.push_back(value);
+        }
+        let (front, back) = deque.slice_lengths();
+        assert_eq!(front + back, 4);
+        assert!(back > 0);
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.deque.iter()
+        deque.align_to_front();
+        assert_eq!(deque.slice_lengths(), (4, 0));
     }
-}
 
-// Implement IntoIterator for mutable Deque references
-impl<'a, T> IntoIterator for &'a mut Deque<T> {
-    type Item = &'a mut T;
-    type IntoIter = std::collections::vec_deque::IterMut<'a, T>;
+    #[test]
+    fn test_clear_and_shrink() {
+        let mut deque: Deque<i32> = Deque::new(1024);
+        deque.extend(0..10);
+        deque.clear_and_shrink();
+        assert!(deque.is_empty());
+        assert!(deque.capacity() < 1024);
+        assert!(deque.heap_size() < 1024 * std::mem::size_of::<i32>());
 
-    fn into_iter(self) -> Self::IntoIter {
-        self.deque.iter_mut()
+        let mut deque: Deque<i32> = Deque::new(1024);
+        deque.extend(0..10);
+        deque.clear();
+        assert!(deque.capacity() >= 1024);
     }
-}
 
-// Implement FromIterator to create Deque from an iterator
-impl<T> FromIterator<T> for Deque<T> {
-    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
-        let deque: VecDeque<T> = iter.into_iter().collect();
-        let maxlen = deque.len();
-        Self::from_vec_deque(deque, maxlen)
+    #[test]
+    fn test_write_wrapping() {
+        let mut deque: Deque<i32> = (vec![0, 0, 0], 3).into();
+        for index in 0..5 {
+            deque.write_wrapping(index, i32::try_from(index).expect("Index fits in i32") + 10);
+        }
+        assert_eq!(deque, Deque::from_vec(vec![13, 14, 12], 3));
     }
-}
 
-#[cfg(feature = "serde")]
-impl<T: Serialize> Serialize for Deque<T> {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        self.deque.serialize(serializer)
+    #[test]
+    #[should_panic(expected = "write_wrapping requires a full deque")]
+    fn test_write_wrapping_not_full() {
+        let mut deque: Deque<i32> = (vec![0, 0], 3).into();
+        deque.write_wrapping(0, 1);
     }
-}
 
-#[cfg(feature = "serde")]
-impl<'de, T: Deserialize<'de>> Deserialize<'de> for Deque<T> {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        let deque = VecDeque::deserialize(deserializer)?;
-        let maxlen = deque.len();
-        Ok(Self { deque, maxlen })
+    #[test]
+    fn test_apply_indexed() {
+        let mut deque: Deque<usize> = (vec![10, 10, 10], 3).into();
+        deque.apply_indexed(|index, value| *value += index);
+        assert_eq!(deque, Deque::from_vec(vec![10, 11, 12], 3));
+
+        let mut deque: Deque<usize> = Deque::new(4);
+        deque.push_back(1);
+        deque.push_front(0);
+        let mut seen = Vec::new();
+        deque.apply_indexed(|index, value| seen.push((index, *value)));
+        assert_eq!(seen, vec![(0, 0), (1, 1)]);
     }
 }
 
 #[cfg(test)]
-mod initialization_tests {
+mod window_tests {
     use super::Deque;
-    use std::collections::VecDeque;
 
     #[test]
-    fn test_initialization() {
-        let mut deque: Deque<i32> = Deque::new(1024);
-        assert_eq!(deque.len(), 0);
-        assert_eq!(deque.maxlen(), 1024);
+    fn test_rolling_sum() {
+        let mut deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+        assert_eq!(deque.rolling_sum(2), vec![3, 5, 7]);
+        assert_eq!(deque.rolling_sum(4), vec![10]);
+        assert!(deque.rolling_sum(5).is_empty());
+    }
 
-        deque = Deque::from(1, 128);
-        assert_eq!(deque.len(), 1);
-        assert_eq!(deque.maxlen(), 128);
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn test_rolling_sum_zero_window() {
+        let mut deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+        let _ = deque.rolling_sum(0);
+    }
 
-        let deque = Deque::from_vec(vec![1, 2, 3, 4, 5], 5);
-        assert_eq!(deque.len(), 5);
-        assert_eq!(deque.maxlen(), 5);
+    #[test]
+    fn test_windows_owned() {
+        let mut deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+        assert_eq!(deque.windows_owned(2), vec![vec![1, 2], vec![2, 3]]);
+        assert_eq!(deque.windows_owned(3), vec![vec![1, 2, 3]]);
+        assert!(deque.windows_owned(4).is_empty());
+    }
 
-        let vec_deque = VecDeque::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
-        let deque = Deque::from_vec_deque(vec_deque, 100);
-        assert_eq!(deque.len(), 10);
-        assert_eq!(deque.maxlen(), 100);
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn test_windows_owned_zero_size() {
+        let mut deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+        let _ = deque.windows_owned(0);
     }
 
     #[test]
-    fn test_initialization_with_into() {
-        let deque: Deque<&str> = ("a", 1024).into();
-        assert_eq!(deque.len(), 1);
-        assert_eq!(deque.maxlen(), 1024);
+    fn test_rolling_into_reuses_output() {
+        let mut out = Vec::new();
 
-        let mut deque: Deque<usize> = ([1, 2, 3, 4, 5, 6, 7, 8, 9, 10], 5).into();
-        assert_eq!(deque.len(), 5);
-        assert_eq!(deque.maxlen(), 5);
+        let mut deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+        deque.rolling_into(2, &mut out, |window| window.iter().sum::<i32>());
+        assert_eq!(out, vec![3, 5, 7]);
 
-        let vec_deque = VecDeque::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
-        deque = (vec_deque, 100).into();
-        assert_eq!(deque.len(), 10);
-        assert_eq!(deque.maxlen(), 100);
+        let mut deque: Deque<i32> = (vec![10, 20, 30], 4).into();
+        deque.rolling_into(3, &mut out, |window| window.iter().sum::<i32>());
+        assert_eq!(out, vec![60]);
     }
 
     #[test]
-    fn test_initialization_from_longer_than_maxlen() {
-        let deque = Deque::from_vec(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10], 5);
-        assert_eq!(deque.len(), 5);
-        assert_eq!(deque.maxlen(), 5);
-
-        let vec_deque = VecDeque::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
-        let deque = Deque::from_vec_deque(vec_deque, 8);
-        assert_eq!(deque.len(), 8);
-        assert_eq!(deque.maxlen(), 8);
+    fn test_rolling_min() {
+        let mut deque: Deque<i32> = (vec![4, 2, 3, 1, 5], 5).into();
+        assert_eq!(deque.rolling_min(3), vec![2, 1, 1]);
+        assert_eq!(deque.rolling_min(1), vec![4, 2, 3, 1, 5]);
+        assert_eq!(deque.rolling_min(5), vec![1]);
+        assert!(deque.rolling_min(6).is_empty());
+    }
 
-        let deque: Deque<i32> = ([1, 2, 3, 4, 5, 6, 7, 8, 9, 10], 5).into();
-        assert_eq!(deque.len(), 5);
-        assert_eq!(deque.maxlen(), 5);
+    #[test]
+    fn test_rolling_max() {
+        let mut deque: Deque<i32> = (vec![4, 2, 3, 1, 5], 5).into();
+        assert_eq!(deque.rolling_max(3), vec![4, 3, 5]);
+        assert_eq!(deque.rolling_max(2), vec![4, 3, 3, 5]);
 
-        let deque: Deque<i32> = (vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10], 6).into();
-        assert_eq!(deque.len(), 6);
-        assert_eq!(deque.maxlen(), 6);
+        let mut deque: Deque<i32> = (vec![2, 2, 1, 2], 5).into();
+        assert_eq!(deque.rolling_max(2), vec![2, 2, 2]);
+        assert_eq!(deque.rolling_min(2), vec![2, 1, 1]);
+    }
 
-        let vec_deque = VecDeque::from([1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
-        let deque: Deque<i32> = (vec_deque, 8).into();
-        assert_eq!(deque.len(), 8);
-        assert_eq!(deque.maxlen(), 8);
-        assert_eq!(deque.front(), Some(&1));
-        assert_eq!(deque.back(), Some(&8));
+    #[test]
+    fn test_rolling_min_max_matches_naive() {
+        let mut deque: Deque<i32> = Deque::new(32);
+        for value in [7, 3, 9, 1, 1, 8, 2, 6, 5, 4, 0, 9, 3, 3, 7] {
+            deque.push_back(value);
+        }
+        for window in 1..=deque.len() {
+            let windows = deque.windows_owned(window);
+            let expected_min: Vec<i32> = windows
+                .iter()
+                .filter_map(|w| w.iter().min().copied())
+                .collect();
+            let expected_max: Vec<i32> = windows
+                .iter()
+                .filter_map(|w| w.iter().max().copied())
+                .collect();
+            assert_eq!(deque.rolling_min(window), expected_min);
+            assert_eq!(deque.rolling_max(window), expected_max);
+        }
     }
-}
 
-#[cfg(test)]
-mod comparison_tests {
-    use super::Deque;
-    use std::collections::VecDeque;
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn test_rolling_min_zero_window() {
+        let mut deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+        let _ = deque.rolling_min(0);
+    }
 
     #[test]
-    fn test_equal_deques() {
-        let mut deque1: Deque<i32> = Deque::new(3);
-        deque1.push_back(1);
-        deque1.push_back(2);
-        deque1.push_back(3);
+    fn test_moving_average() {
+        let mut deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+        assert_eq!(deque.moving_average(2), vec![1.5, 2.5, 3.5]);
+        assert_eq!(deque.moving_average(4), vec![2.5]);
+        assert!(deque.moving_average(5).is_empty());
 
-        let mut deque2: Deque<i32> = Deque::new(3);
-        deque2.push_back(1);
-        deque2.push_back(2);
-        deque2.push_back(3);
+        let mut deque: Deque<f32> = (vec![0.5, 1.5], 2).into();
+        assert_eq!(deque.moving_average(1), vec![0.5, 1.5]);
+    }
 
-        assert_eq!(
-            deque1, deque2,
-            "Deques with the same elements should be equal"
-        );
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn test_moving_average_zero_window() {
+        let mut deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+        let _ = deque.moving_average(0);
     }
 
     #[test]
-    fn test_unequal_deques_different_elements() {
-        let mut deque1: Deque<i32> = Deque::new(3);
-        deque1.push_back(1);
-        deque1.push_back(2);
-        deque1.push_back(3);
+    fn test_chunks_exact() {
+        let mut deque: Deque<i32> = (vec![1, 2, 3, 4, 5], 5).into();
+        let chunks: Vec<&[i32]> = deque.chunks_exact(2).collect();
+        assert_eq!(chunks, vec![&[1, 2][..], &[3, 4][..]]);
+        assert_eq!(deque.chunks_exact(2).remainder(), &[5]);
+        assert_eq!(deque.chunks_exact(6).count(), 0);
+    }
 
-        let mut deque2: Deque<i32> = Deque::new(3);
-        deque2.push_back(4);
-        deque2.push_back(5);
-        deque2.push_back(6);
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn test_chunks_exact_zero_size() {
+        let mut deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+        let _ = deque.chunks_exact(0);
+    }
 
-        assert_ne!(
-            deque1, deque2,
-            "Deques with different elements should not be equal"
-        );
+    #[test]
+    fn test_last_window() {
+        let deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+        assert_eq!(deque.last_window(2), Some(vec![&3, &4]));
+        assert_eq!(deque.last_window(4), Some(vec![&1, &2, &3, &4]));
+        assert_eq!(deque.last_window(5), None);
     }
 
     #[test]
-    fn test_unequal_deques_different_lengths() {
-        let mut deque1: Deque<i32> = Deque::new(3);
-        deque1.push_back(1);
-        deque1.push_back(2);
+    fn test_window_fold() {
+        let mut deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+        assert_eq!(deque.window_fold(2, 1, |acc, x| acc * x), vec![2, 6, 12]);
+        assert_eq!(deque.window_fold(3, 0, |acc, x| acc + x), vec![6, 9]);
+        assert!(deque.window_fold(5, 0, |acc, x| acc + x).is_empty());
+    }
 
-        let mut deque2: Deque<i32> = Deque::new(3);
-        deque2.push_back(1);
-        deque2.push_back(2);
-        deque2.push_back(3);
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn test_window_fold_zero_window() {
+        let mut deque: Deque<i32> = (vec![1, 2, 3, 4], 4).into();
+        let _ = deque.window_fold(0, 0, |acc, x| acc + x);
+    }
 
-        assert_ne!(
-            deque1, deque2,
-            "Deques with different lengths should not be equal"
+    #[test]
+    fn test_rolling_variance() {
+        let mut deque: Deque<i32> = (vec![2, 4, 4, 4, 5, 5, 7, 9], 8).into();
+        assert_eq!(deque.rolling_variance(8), vec![4.0]);
+        assert_eq!(deque.rolling_std(8), vec![2.0]);
+        assert_eq!(
+            deque.rolling_variance(2),
+            vec![1.0, 0.0, 0.0, 0.25, 0.0, 1.0, 1.0]
         );
+        assert_eq!(deque.rolling_variance(1), vec![0.0; 8]);
+        assert!(deque.rolling_std(9).is_empty());
     }
 
     #[test]
-    fn test_empty_deques_are_equal() {
-        let deque1: Deque<i32> = Deque::new(3);
-        let deque2: Deque<i32> = Deque::new(3);
+    #[should_panic(expected = "window size must be non-zero")]
+    fn test_rolling_variance_zero_window() {
+        let mut deque: Deque<i32> = (vec![1, 2, 3], 3).into();
+        let _ = deque.rolling_variance(0);
+    }
+}
 
-        assert_eq!(deque1, deque2, "Empty deques should be equal");
+#[cfg(test)]
+mod view_tests {
+    use super::Deque;
+
+    #[test]
+    fn test_view() {
+        let deque: Deque<i32> = (vec![1, 2, 3], 5).into();
+        let view = deque.view();
+        assert_eq!(view.len(), 3);
+        assert_eq!(view.maxlen(), 5);
+        assert!(!view.is_empty());
+        assert_eq!(view.get(1), Some(&2));
+        assert_eq!(view.get(3), None);
+        assert_eq!(view.front(), Some(&1));
+        assert_eq!(view.back(), Some(&3));
+        assert_eq!(view.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
     }
 
     #[test]
-    fn test_partial_eq_with_subsequent_push_back() {
-        let mut deque1: Deque<i32> = Deque::new(3);
-        deque1.push_back(1);
-        deque1.push_back(2);
-        deque1.push_back(3);
+    fn test_view_of_empty_deque() {
+        let deque: Deque<i32> = Deque::new(2);
+        let view = deque.view();
+        let copy = view;
+        assert!(view.is_empty());
+        assert_eq!(copy.front(), None);
+        assert_eq!(copy.back(), None);
+        assert_eq!(copy.maxlen(), 2);
+        assert_eq!(copy.into_iter().count(), 0);
+    }
+}
 
-        let mut deque2: Deque<i32> = Deque::new(3);
-        deque2.push_back(1);
-        deque2.push_back(2);
+#[cfg(feature = "io")]
+#[cfg(test)]
+mod io_tests {
+    use super::{Deque, OverflowPolicy};
+    use std::io::{Read, Write};
 
-        assert_ne!(
-            deque1, deque2,
-            "Deque with missing elements should not be equal"
-        );
+    #[test]
+    fn test_write_then_read_fifo() {
+        let mut deque: Deque<u8> = Deque::new(8);
+        assert_eq!(deque.write(b"abc").expect("Write failed"), 3);
+        assert_eq!(deque.write(b"de").expect("Write failed"), 2);
 
-        deque2.push_back(3);
-        assert_eq!(
-            deque1, deque2,
-            "After pushing the same element, deques should be equal"
-        );
+        let mut buf = [0; 4];
+        assert_eq!(deque.read(&mut buf).expect("Read failed"), 4);
+        assert_eq!(&buf, b"abcd");
+        assert_eq!(deque.read(&mut buf).expect("Read failed"), 1);
+        assert_eq!(buf[0], b'e');
+        assert_eq!(deque.read(&mut buf).expect("Read failed"), 0);
     }
 
     #[test]
-    fn test_deque_eq_vecdeque() {
-        let deque: Deque<i32> = Deque::from_vec(vec![5, 6, 7], 3);
-        let vec_deque: VecDeque<i32> = vec![5, 6, 7].into_iter().collect();
+    fn test_write_evicts_oldest() {
+        let mut deque: Deque<u8> = Deque::new(3);
+        assert_eq!(deque.write(b"abcde").expect("Write failed"), 5);
 
-        assert_eq!(
-            deque, vec_deque,
-            "Deque should be equal to VecDeque with the same elements"
-        );
+        let mut out = Vec::new();
+        deque.read_to_end(&mut out).expect("Read failed");
+        assert_eq!(out, b"cde");
+        assert!(deque.is_empty());
     }
 
     #[test]
-    fn test_vecdeque_eq_deque() {
-        let mut deque1: Deque<i32> = Deque::new(10);
-        deque1.push_back(1);
-        deque1.push_back(2);
-        deque1.push_back(3);
-
-        let mut vecdeque: VecDeque<i32> = VecDeque::new();
-        vecdeque.push_back(1);
-        vecdeque.push_back(2);
-        vecdeque.push_back(3);
+    fn test_write_reject_new() {
+        let mut deque: Deque<u8> = Deque::with_policy(3, OverflowPolicy::RejectNew);
+        assert_eq!(deque.write(b"abcde").expect("Write failed"), 3);
+        assert_eq!(deque.write(b"f").expect("Write failed"), 0);
+        assert!(deque.write_all(b"g").is_err());
 
-        assert_eq!(
-            vecdeque, deque1,
-            "VecDeque should be equal to Deque with the same elements"
-        );
+        let mut out = Vec::new();
+        deque.read_to_end(&mut out).expect("Read failed");
+        assert_eq!(out, b"abc");
+        assert_eq!(deque.write(b"xy").expect("Write failed"), 2);
     }
 
     #[test]
-    fn test_unequal_deque_and_vecdeque() {
-        let mut deque1: Deque<i32> = Deque::new(3);
-        deque1.push_back(1);
-        deque1.push_back(2);
-
-        let mut vecdeque: VecDeque<i32> = VecDeque::new();
-        vecdeque.push_back(3);
-        vecdeque.push_back(4);
-
-        assert_ne!(
-            deque1, vecdeque,
-            "Deque and VecDeque with different elements should not be equal"
-        );
+    fn test_write_zero_maxlen() {
+        let mut deque: Deque<u8> = Deque::new(0);
+        assert_eq!(deque.write(b"abc").expect("Write failed"), 0);
+        assert!(deque.is_empty());
+        assert!(deque.write_all(b"abc").is_err());
     }
 }
 
@@ -895,4 +5627,79 @@ mod serde_tests {
         assert_eq!(deserialized.get(1), Some(&20));
         assert_eq!(deserialized.get(2), Some(&30));
     }
+
+    #[test]
+    fn test_deserialize_struct_form() {
+        let data = r#"{"maxlen":5,"items":[1,2,3]}"#;
+        let deque: Deque<i32> = serde_json::from_str(data).expect("Failed to deserialize Deque");
+        assert_eq!(deque.len(), 3);
+        assert_eq!(deque.maxlen(), 5);
+        assert_eq!(deque.get(0), Some(&1));
+        assert_eq!(deque.get(2), Some(&3));
+    }
+
+    #[test]
+    fn test_deserialize_struct_and_array_forms_are_equivalent() {
+        let from_array: Deque<i32> =
+            serde_json::from_str("[1,2,3]").expect("Failed to deserialize Deque");
+        let from_struct: Deque<i32> = serde_json::from_str(r#"{"items":[1,2,3],"maxlen":3}"#)
+            .expect("Failed to deserialize Deque");
+        assert_eq!(from_array, from_struct);
+        assert_eq!(from_array.maxlen(), from_struct.maxlen());
+    }
+
+    #[test]
+    fn test_deserialize_struct_form_truncates_to_maxlen() {
+        let data = r#"{"maxlen":2,"items":[1,2,3]}"#;
+        let deque: Deque<i32> = serde_json::from_str(data).expect("Failed to deserialize Deque");
+        assert_eq!(deque.len(), 2);
+        assert_eq!(deque.maxlen(), 2);
+    }
+
+    #[test]
+    fn test_deserialize_struct_form_missing_field() {
+        let result: Result<Deque<i32>, _> = serde_json::from_str(r#"{"items":[1,2,3]}"#);
+        let error = result.expect_err("Missing maxlen should fail");
+        assert!(error.to_string().contains("missing field `maxlen`"));
+    }
+
+    /// A deserializer for a compact binary-like format that cannot describe its own structure.
+    struct SeqOnlyDeserializer(Vec<i32>);
+
+    impl<'de> serde::Deserializer<'de> for SeqOnlyDeserializer {
+        type Error = serde::de::value::Error;
+
+        fn deserialize_any<V>(self, _visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: serde::de::Visitor<'de>,
+        {
+            Err(serde::de::Error::custom("deserialize_any is not supported"))
+        }
+
+        fn deserialize_seq<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+        where
+            V: serde::de::Visitor<'de>,
+        {
+            visitor.visit_seq(serde::de::value::SeqDeserializer::new(self.0.into_iter()))
+        }
+
+        fn is_human_readable(&self) -> bool {
+            false
+        }
+
+        serde::forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+            bytes byte_buf option unit unit_struct newtype_struct tuple
+            tuple_struct map struct enum identifier ignored_any
+        }
+    }
+
+    #[test]
+    fn test_deserialize_non_self_describing_format() {
+        let deque: Deque<i32> = serde::Deserialize::deserialize(SeqOnlyDeserializer(vec![1, 2, 3]))
+            .expect("Failed to deserialize Deque");
+        assert_eq!(deque.len(), 3);
+        assert_eq!(deque.maxlen(), 3);
+        assert_eq!(deque.back(), Some(&3));
+    }
 }